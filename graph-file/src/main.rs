@@ -17,9 +17,9 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     file.read_to_string(&mut serialized_graph)?;
 
-    return match OrientedGraph::<String, String>::deserialize(serialized_graph.as_str()) {
+    match OrientedGraph::<String, String>::deserialize(serialized_graph.as_str()) {
         Ok(graph) => {
-            return match graph.depth_first_search(1) {
+            match graph.depth_first_search(1) {
                 Ok(dfs_result) => {
                     println!("{}", dfs_result);
                     Ok(())
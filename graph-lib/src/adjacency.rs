@@ -0,0 +1,117 @@
+pub mod adjacency {
+    use std::collections::HashMap;
+
+    use crate::edge::edge::DefaultEdge;
+    use crate::error::GraphError;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::{DefaultVertex, VertexId};
+    use crate::weight::weight::Weight;
+
+    /// Dense snapshot of a graph's structure that algorithms run on.
+    ///
+    /// Vertexes are numbered `0..len()` in the order of `GraphView::vertices`, and the
+    /// outgoing edges of every vertex keep the order of `GraphView::out_edges`.
+    pub(crate) struct Adjacency<I> {
+        pub(crate) ids: Vec<I>,
+        pub(crate) index: HashMap<I, usize>,
+        /// Index of the end and id of every edge starting in a vertex.
+        pub(crate) out: Vec<Vec<(usize, usize)>>,
+        /// Index of the start and id of every edge ending in a vertex.
+        pub(crate) incoming: Vec<Vec<(usize, usize)>>,
+    }
+
+    impl<I: VertexId> Adjacency<I> {
+        pub(crate) fn new<T, V, G: GraphView<T, V, I> + ?Sized>(graph: &G) -> Self {
+            let ids: Vec<I> = graph.vertices().map(|vertex| vertex.id()).collect();
+            let index: HashMap<I, usize> = ids
+                .iter()
+                .enumerate()
+                .map(|(index, id)| (id.clone(), index))
+                .collect();
+            let out: Vec<Vec<(usize, usize)>> = ids
+                .iter()
+                .map(|id| {
+                    graph
+                        .out_edges(id.clone())
+                        .filter_map(|edge| Some((index[&edge.end_id()?], edge.id()?)))
+                        .collect()
+                })
+                .collect();
+            let mut incoming = vec![Vec::new(); ids.len()];
+            for (start, edges) in out.iter().enumerate() {
+                for &(end, edge) in edges {
+                    incoming[end].push((start, edge));
+                }
+            }
+            Self {
+                ids,
+                index,
+                out,
+                incoming,
+            }
+        }
+
+        pub(crate) fn len(&self) -> usize {
+            self.ids.len()
+        }
+
+        pub(crate) fn index_of(&self, id: &I) -> Result<usize, GraphError> {
+            self.index
+                .get(id)
+                .copied()
+                .ok_or(GraphError::VertexNotFound)
+        }
+
+        /// Vertexes joined to `vertex` by an edge in either direction.
+        pub(crate) fn undirected(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+            self.out[vertex]
+                .iter()
+                .chain(&self.incoming[vertex])
+                .map(|&(other, _)| other)
+        }
+
+        /// Distinct successors of every vertex, in order of first appearance.
+        pub(crate) fn successors(&self) -> Vec<Vec<usize>> {
+            let mut seen = vec![usize::MAX; self.len()];
+            self.out
+                .iter()
+                .enumerate()
+                .map(|(vertex, edges)| {
+                    edges
+                        .iter()
+                        .filter(|&&(end, _)| std::mem::replace(&mut seen[end], vertex) != vertex)
+                        .map(|&(end, _)| end)
+                        .collect()
+                })
+                .collect()
+        }
+
+        /// Weighted outgoing edges in the same order as `out`. Edges without a value weigh zero.
+        pub(crate) fn weighted<T, V, G, W, F>(&self, graph: &G, weight: F) -> Vec<Vec<(usize, W)>>
+        where
+            G: GraphView<T, V, I> + ?Sized,
+            W: Weight,
+            F: Fn(&V) -> W,
+        {
+            self.out
+                .iter()
+                .map(|edges| {
+                    edges
+                        .iter()
+                        .map(|&(end, edge)| {
+                            let value = graph
+                                .get_edge_by_id(edge)
+                                .and_then(|edge| edge.value().map(&weight));
+                            (end, value.unwrap_or_default())
+                        })
+                        .collect()
+                })
+                .collect()
+        }
+
+        /// Keys per-vertex results by vertex id.
+        pub(crate) fn by_id<R>(&self, values: Vec<R>) -> HashMap<I, R> {
+            self.ids.iter().cloned().zip(values).collect()
+        }
+    }
+}
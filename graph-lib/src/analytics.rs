@@ -0,0 +1,188 @@
+pub mod analytics {
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashMap};
+
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::error::GraphError;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::VertexId;
+    use crate::weight::weight::{Ordered, Weight};
+
+    /// Whole-graph analytics available on every graph representation.
+    pub trait Analytics<T, V, I: VertexId = usize>: GraphView<T, V, I> {
+        /// PageRank of every vertex after a fixed number of power iterations.
+        /// Vertexes without outgoing edges spread their rank evenly over the graph.
+        fn page_rank(&self, damping: f64, iterations: usize) -> HashMap<I, f64> {
+            let adjacency = Adjacency::new(self);
+            let mut ranks = initial_ranks(&adjacency);
+            let degrees = out_degrees(&adjacency);
+            for _ in 0..iterations {
+                let base = rank_base(&adjacency, &degrees, &ranks, damping);
+                ranks = (0..adjacency.len())
+                    .map(|vertex| rank_of(&adjacency, &degrees, &ranks, damping, base, vertex))
+                    .collect();
+            }
+            adjacency.by_id(ranks)
+        }
+
+        /// Length of the shortest path from `source` to every vertex reachable from it
+        /// (Dijkstra). Edges without a value weigh zero.
+        fn shortest_path_lengths<W: Weight, F: Fn(&V) -> W>(
+            &self,
+            source: I,
+            weight: F,
+        ) -> Result<HashMap<I, W>, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let source = adjacency.index_of(&source)?;
+            let weighted = adjacency.weighted(self, weight);
+            check_non_negative(&weighted)?;
+            Ok(lengths_by_id(&adjacency, dijkstra(&weighted, source)))
+        }
+
+        /// Shortest path lengths from every vertex, keyed by source id.
+        fn all_shortest_path_lengths<W: Weight, F: Fn(&V) -> W>(
+            &self,
+            weight: F,
+        ) -> Result<HashMap<I, HashMap<I, W>>, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let weighted = adjacency.weighted(self, weight);
+            check_non_negative(&weighted)?;
+            Ok((0..adjacency.len())
+                .map(|source| {
+                    let lengths = dijkstra(&weighted, source);
+                    (
+                        adjacency.ids[source].clone(),
+                        lengths_by_id(&adjacency, lengths),
+                    )
+                })
+                .collect())
+        }
+    }
+
+    impl<T, V, I: VertexId, G: GraphView<T, V, I>> Analytics<T, V, I> for G {}
+
+    pub(crate) fn initial_ranks<I: VertexId>(adjacency: &Adjacency<I>) -> Vec<f64> {
+        vec![1.0 / adjacency.len() as f64; adjacency.len()]
+    }
+
+    pub(crate) fn out_degrees<I: VertexId>(adjacency: &Adjacency<I>) -> Vec<usize> {
+        adjacency.out.iter().map(Vec::len).collect()
+    }
+
+    /// Rank every vertex receives regardless of its incoming edges.
+    pub(crate) fn rank_base<I: VertexId>(
+        adjacency: &Adjacency<I>,
+        degrees: &[usize],
+        ranks: &[f64],
+        damping: f64,
+    ) -> f64 {
+        let dangling: f64 = (0..adjacency.len())
+            .filter(|&vertex| degrees[vertex] == 0)
+            .map(|vertex| ranks[vertex])
+            .sum();
+        ((1.0 - damping) + damping * dangling) / adjacency.len() as f64
+    }
+
+    pub(crate) fn rank_of<I: VertexId>(
+        adjacency: &Adjacency<I>,
+        degrees: &[usize],
+        ranks: &[f64],
+        damping: f64,
+        base: f64,
+        vertex: usize,
+    ) -> f64 {
+        let incoming: f64 = adjacency.incoming[vertex]
+            .iter()
+            .map(|&(start, _)| ranks[start] / degrees[start] as f64)
+            .sum();
+        base + damping * incoming
+    }
+
+    pub(crate) fn check_non_negative<W: Weight>(
+        weighted: &[Vec<(usize, W)>],
+    ) -> Result<(), GraphError> {
+        if weighted
+            .iter()
+            .flatten()
+            .any(|&(_, weight)| weight < W::default())
+        {
+            return Err(GraphError::NegativeWeightError);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn dijkstra<W: Weight>(
+        weighted: &[Vec<(usize, W)>],
+        source: usize,
+    ) -> Vec<Option<W>> {
+        dijkstra_tree(weighted, source).0
+    }
+
+    /// Shortest path lengths from `source` together with the previous vertex on the
+    /// shortest path to every reached vertex, `usize::MAX` for the source itself.
+    pub(crate) fn dijkstra_tree<W: Weight>(
+        weighted: &[Vec<(usize, W)>],
+        source: usize,
+    ) -> (Vec<Option<W>>, Vec<usize>) {
+        let mut lengths = vec![None; weighted.len()];
+        let mut parents = vec![usize::MAX; weighted.len()];
+        let mut done = vec![false; weighted.len()];
+        let mut heap = BinaryHeap::from([Reverse((Ordered(W::default()), source))]);
+        lengths[source] = Some(W::default());
+        while let Some(Reverse((Ordered(length), vertex))) = heap.pop() {
+            if std::mem::replace(&mut done[vertex], true) {
+                continue;
+            }
+            for &(next, weight) in &weighted[vertex] {
+                let candidate = length + weight;
+                if lengths[next].is_none_or(|known| candidate < known) {
+                    lengths[next] = Some(candidate);
+                    parents[next] = vertex;
+                    heap.push(Reverse((Ordered(candidate), next)));
+                }
+            }
+        }
+        (lengths, parents)
+    }
+
+    /// Shortest path lengths from `source` that tolerate negative weights (Bellman–Ford).
+    pub(crate) fn bellman_ford<W: Weight>(
+        weighted: &[Vec<(usize, W)>],
+        source: usize,
+    ) -> Result<Vec<Option<W>>, GraphError> {
+        let mut lengths = vec![None; weighted.len()];
+        lengths[source] = Some(W::default());
+        // Without negative cycles, lengths settle within `len() - 1` rounds.
+        for _ in 0..weighted.len() {
+            let mut changed = false;
+            for (vertex, edges) in weighted.iter().enumerate() {
+                let Some(length) = lengths[vertex] else {
+                    continue;
+                };
+                for &(next, weight) in edges {
+                    let candidate = length + weight;
+                    if lengths[next].is_none_or(|known| candidate < known) {
+                        lengths[next] = Some(candidate);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                return Ok(lengths);
+            }
+        }
+        Err(GraphError::NegativeCycleError)
+    }
+
+    pub(crate) fn lengths_by_id<I: VertexId, W: Weight>(
+        adjacency: &Adjacency<I>,
+        lengths: Vec<Option<W>>,
+    ) -> HashMap<I, W> {
+        adjacency
+            .ids
+            .iter()
+            .zip(lengths)
+            .filter_map(|(id, length)| Some((id.clone(), length?)))
+            .collect()
+    }
+}
@@ -0,0 +1,485 @@
+pub mod arena {
+    use std::collections::{BTreeMap, HashMap};
+    use std::fmt::Debug;
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    use crate::edge::edge::{DefaultEdge, DefaultOrientedEdge};
+    use crate::error::{
+        EdgeParseError, GraphError, GraphParseError, SerializationError, VertexParseError,
+    };
+    use crate::graph::graph::{DefaultGraph, GraphPolicy, GraphView};
+    use crate::serde::serde_graph::{
+        deserialize_into, edge_line, parse_edge, parse_vertex, serialize_from, vertex_line,
+        DeserializeGraph, SerializeGraph,
+    };
+    use crate::vertex::vertex::{DefaultVertex, IdAllocator};
+
+    #[derive(Debug, Clone)]
+    pub struct ArenaVertex<T, V> {
+        id: usize,
+        value: T,
+        /// Slot and id of every edge that starts or ends in this vertex.
+        pub(crate) edges: Vec<(usize, usize)>,
+        edge_value: PhantomData<fn() -> V>,
+    }
+
+    impl<T, V> ArenaVertex<T, V> {
+        pub(crate) fn new(id: usize, value: T) -> Self {
+            Self {
+                id,
+                value,
+                edges: Vec::new(),
+                edge_value: PhantomData,
+            }
+        }
+    }
+
+    impl<T, V> DefaultVertex<T, V> for ArenaVertex<T, V> {
+        fn id(&self) -> usize {
+            self.id
+        }
+
+        fn value(&self) -> &T {
+            &self.value
+        }
+
+        fn value_mut(&mut self) -> &mut T {
+            &mut self.value
+        }
+
+        fn set_value(&mut self, value: T) {
+            self.value = value
+        }
+
+        fn edge_ids(&self) -> impl Iterator<Item = usize> {
+            self.edges.iter().map(|&(_, id)| id)
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct ArenaEdge<T, V> {
+        id: Option<usize>,
+        pub(crate) start: usize,
+        pub(crate) end: usize,
+        value: Option<V>,
+        vertex_value: PhantomData<fn() -> T>,
+    }
+
+    impl<T, V> ArenaEdge<T, V> {
+        pub(crate) fn new(start: usize, end: usize, value: Option<V>) -> Self {
+            Self {
+                id: None,
+                start,
+                end,
+                value,
+                vertex_value: PhantomData,
+            }
+        }
+    }
+
+    impl<T, V> DefaultEdge<T, V> for ArenaEdge<T, V> {
+        fn id(&self) -> Option<usize> {
+            self.id
+        }
+
+        fn end_id(&self) -> Option<usize> {
+            Some(self.end)
+        }
+
+        fn value(&self) -> Option<&V> {
+            self.value.as_ref()
+        }
+
+        fn value_mut(&mut self) -> Option<&mut V> {
+            self.value.as_mut()
+        }
+    }
+
+    impl<T, V> DefaultOrientedEdge<T, V> for ArenaEdge<T, V> {
+        fn start_id(&self) -> Option<usize> {
+            Some(self.start)
+        }
+    }
+
+    /// Graph keeping vertexes and edges in plain vectors and linking them by index,
+    /// so it needs no runtime borrow checks and is `Send`/`Sync` whenever `T` and `V` are.
+    ///
+    /// Removed vertexes and edges leave an empty slot behind. Once empty slots outnumber
+    /// the live ones the vectors are compacted, keeping the order vertexes were added in.
+    #[derive(Debug, Clone)]
+    pub struct ArenaGraph<T, V> {
+        pub(crate) vertices: Vec<Option<ArenaVertex<T, V>>>,
+        pub(crate) vertex_slots: HashMap<usize, usize>,
+        pub(crate) edges: Vec<Option<ArenaEdge<T, V>>>,
+        pub(crate) edge_slots: BTreeMap<usize, usize>,
+        next_edge_id: usize,
+        vertex_ids: IdAllocator,
+        policy: GraphPolicy,
+    }
+
+    impl<T, V> Default for ArenaGraph<T, V> {
+        fn default() -> Self {
+            Self::with_policy(GraphPolicy::default())
+        }
+    }
+
+    impl<T, V> ArenaGraph<T, V> {
+        pub fn with_policy(policy: GraphPolicy) -> Self {
+            Self {
+                vertices: Vec::new(),
+                vertex_slots: HashMap::new(),
+                edges: Vec::new(),
+                edge_slots: BTreeMap::new(),
+                next_edge_id: 0,
+                vertex_ids: IdAllocator::default(),
+                policy,
+            }
+        }
+
+        pub fn multigraph() -> Self {
+            Self::with_policy(GraphPolicy::multigraph())
+        }
+
+        pub fn policy(&self) -> GraphPolicy {
+            self.policy
+        }
+
+        pub(crate) fn next_edge_id(&self) -> usize {
+            self.next_edge_id
+        }
+
+        pub(crate) fn vertex_ids(&self) -> &IdAllocator {
+            &self.vertex_ids
+        }
+
+        fn vertex(&self, id: usize) -> Option<&ArenaVertex<T, V>> {
+            self.vertex_slots
+                .get(&id)
+                .and_then(|&slot| self.vertices[slot].as_ref())
+        }
+
+        pub(crate) fn edge_at(&self, slot: usize) -> &ArenaEdge<T, V> {
+            self.edges[slot]
+                .as_ref()
+                .expect("vertexes only reference live edges")
+        }
+
+        fn out_edge_slots(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+            self.vertex(id)
+                .into_iter()
+                .flat_map(|vertex| vertex.edges.iter().map(|&(slot, _)| slot))
+                .filter(move |&slot| self.edge_at(slot).start == id)
+        }
+
+        /// Drops every empty slot left by removals and releases the spare capacity.
+        pub fn shrink_to_fit(&mut self) {
+            self.compact();
+            self.vertices.shrink_to_fit();
+            self.vertex_slots.shrink_to_fit();
+            self.edges.shrink_to_fit();
+        }
+
+        fn compact(&mut self) {
+            self.vertices.retain(Option::is_some);
+            self.edges.retain(Option::is_some);
+            self.vertex_slots.clear();
+            for (slot, vertex) in self.vertices.iter().flatten().enumerate() {
+                self.vertex_slots.insert(vertex.id, slot);
+            }
+            for (slot, edge) in self.edges.iter().flatten().enumerate() {
+                if let Some(id) = edge.id {
+                    self.edge_slots.insert(id, slot);
+                }
+            }
+            for vertex in self.vertices.iter_mut().flatten() {
+                for (slot, id) in vertex.edges.iter_mut() {
+                    *slot = self.edge_slots[id];
+                }
+            }
+        }
+
+        fn compact_if_sparse(&mut self) {
+            if self.vertices.len() > 2 * self.vertex_slots.len()
+                || self.edges.len() > 2 * self.edge_slots.len()
+            {
+                self.compact();
+            }
+        }
+
+        fn push_vertex(&mut self, vertex: ArenaVertex<T, V>) -> Result<(), GraphError> {
+            if self.vertex_slots.contains_key(&vertex.id) {
+                return Err(GraphError::VertexExistsError);
+            }
+            self.vertex_ids.reserve(vertex.id);
+            self.vertex_slots.insert(vertex.id, self.vertices.len());
+            self.vertices.push(Some(vertex));
+            Ok(())
+        }
+
+        fn insert_edge(
+            &mut self,
+            id: usize,
+            start: usize,
+            end: usize,
+            value: Option<V>,
+        ) -> Result<usize, GraphError> {
+            let next_edge_id = id.checked_add(1).ok_or(GraphError::EdgeIdsExhaustedError)?;
+            let exists = self
+                .out_edge_slots(start)
+                .any(|slot| self.edge_at(slot).end == end);
+            self.policy.check(&start, &end, exists)?;
+
+            if let (Some(&start_slot), Some(&end_slot)) =
+                (self.vertex_slots.get(&start), self.vertex_slots.get(&end))
+            {
+                let slot = self.edges.len();
+                let mut edge = ArenaEdge::new(start, end, value);
+                edge.id = Some(id);
+                self.edges.push(Some(edge));
+                self.edge_slots.insert(id, slot);
+
+                if let Some(vertex) = self.vertices[start_slot].as_mut() {
+                    vertex.edges.push((slot, id));
+                }
+                if start_slot != end_slot {
+                    if let Some(vertex) = self.vertices[end_slot].as_mut() {
+                        vertex.edges.push((slot, id));
+                    }
+                }
+                self.next_edge_id = self.next_edge_id.max(next_edge_id);
+                return Ok(id);
+            }
+            Err(GraphError::VertexNotFound)
+        }
+
+        fn detach_edge(&mut self, id: usize) -> Result<(), GraphError> {
+            let slot = self
+                .edge_slots
+                .remove(&id)
+                .ok_or(GraphError::EdgeNotFound)?;
+            if let Some(edge) = self.edges[slot].take() {
+                for vertex_id in [edge.start, edge.end] {
+                    if let Some(&vertex_slot) = self.vertex_slots.get(&vertex_id) {
+                        if let Some(vertex) = self.vertices[vertex_slot].as_mut() {
+                            vertex.edges.retain(|&(e, _)| e != slot);
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl<T, V> GraphView<T, V> for ArenaGraph<T, V> {
+        type VertexType = ArenaVertex<T, V>;
+        type EdgeType = ArenaEdge<T, V>;
+        type VertexRef<'a>
+            = &'a ArenaVertex<T, V>
+        where
+            Self: 'a;
+        type EdgeRef<'a>
+            = &'a ArenaEdge<T, V>
+        where
+            Self: 'a;
+
+        fn vertex_count(&self) -> usize {
+            self.vertex_slots.len()
+        }
+
+        fn edges_count(&self) -> usize {
+            self.edge_slots.len()
+        }
+
+        fn vertices(&self) -> impl Iterator<Item = Self::VertexRef<'_>> {
+            self.vertices.iter().flatten()
+        }
+
+        fn edges(&self) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.edge_slots.values().map(|&slot| self.edge_at(slot))
+        }
+
+        fn edge_ids(&self) -> impl Iterator<Item = usize> {
+            self.edge_slots.keys().copied()
+        }
+
+        fn neighbors(&self, id: usize) -> impl Iterator<Item = usize> {
+            self.out_edge_slots(id).map(|slot| self.edge_at(slot).end)
+        }
+
+        fn out_edges(&self, id: usize) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.out_edge_slots(id).map(|slot| self.edge_at(slot))
+        }
+
+        fn get_vertex_by_id(&self, id: usize) -> Option<Self::VertexRef<'_>> {
+            self.vertex(id)
+        }
+
+        fn get_edge_by_id(&self, id: usize) -> Option<Self::EdgeRef<'_>> {
+            self.edge_slots.get(&id).map(|&slot| self.edge_at(slot))
+        }
+
+        fn get_edge_by_vertexes_id(&self, start: usize, end: usize) -> Option<Self::EdgeRef<'_>> {
+            self.get_edges_by_vertexes_id(start, end).next()
+        }
+
+        fn get_edges_by_vertexes_id(
+            &self,
+            start: usize,
+            end: usize,
+        ) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.out_edges(start).filter(move |edge| edge.end == end)
+        }
+    }
+
+    impl<T, V: Clone> DefaultGraph<T, V> for ArenaGraph<T, V> {
+        fn add_edge(&mut self, edge: Self::EdgeType) -> Result<usize, GraphError> {
+            match edge.id {
+                Some(id) if self.edge_slots.contains_key(&id) => Err(GraphError::EdgeExistsError),
+                Some(id) => self.insert_edge(id, edge.start, edge.end, edge.value),
+                None => self.insert_edge(self.next_edge_id, edge.start, edge.end, edge.value),
+            }
+        }
+
+        fn add_edge_with_vertex_id(
+            &mut self,
+            start: usize,
+            end: usize,
+            value: Option<V>,
+        ) -> Result<usize, GraphError> {
+            self.insert_edge(self.next_edge_id, start, end, value)
+        }
+
+        fn remove_edge_by_id(&mut self, id: usize) -> Result<(), GraphError> {
+            self.detach_edge(id)?;
+            self.compact_if_sparse();
+            Ok(())
+        }
+
+        fn remove_edge_by_vertexes(
+            &mut self,
+            start_vertex_id: usize,
+            end_vertex_id: usize,
+        ) -> Result<(), GraphError> {
+            let id = self
+                .get_edge_by_vertexes_id(start_vertex_id, end_vertex_id)
+                .and_then(|edge| edge.id)
+                .ok_or(GraphError::EdgeNotFound)?;
+            self.detach_edge(id)?;
+            self.compact_if_sparse();
+            Ok(())
+        }
+
+        fn add_vertex(&mut self, vertex: Self::VertexType) -> Result<(), GraphError> {
+            self.push_vertex(vertex)
+        }
+
+        fn add_raw_vertex(&mut self, id: usize, value: T) -> Result<(), GraphError> {
+            self.push_vertex(ArenaVertex::new(id, value))
+        }
+
+        fn insert_vertex(&mut self, value: T) -> Result<usize, GraphError> {
+            let id = self.vertex_ids.next()?;
+            self.push_vertex(ArenaVertex::new(id, value))?;
+            Ok(id)
+        }
+
+        fn remove_vertex_by_id(&mut self, id: usize) -> Result<(), GraphError> {
+            let slot = self
+                .vertex_slots
+                .get(&id)
+                .copied()
+                .ok_or(GraphError::VertexNotFound)?;
+            let edge_ids: Vec<usize> = self.vertices[slot]
+                .iter()
+                .flat_map(|vertex| vertex.edges.iter().map(|&(_, id)| id))
+                .collect();
+            for edge_id in edge_ids {
+                self.detach_edge(edge_id)?;
+            }
+
+            self.vertices[slot] = None;
+            self.vertex_slots.remove(&id);
+            if self.policy.reuse_vertex_ids {
+                self.vertex_ids.release(id);
+            }
+            self.compact_if_sparse();
+            Ok(())
+        }
+
+        fn update_vertex<F: FnOnce(&mut T)>(&mut self, id: usize, f: F) -> Result<(), GraphError> {
+            let vertex = self
+                .vertex_slots
+                .get(&id)
+                .and_then(|&slot| self.vertices[slot].as_mut())
+                .ok_or(GraphError::VertexNotFound)?;
+            f(&mut vertex.value);
+            Ok(())
+        }
+
+        fn update_edge<F: FnOnce(Option<&mut V>)>(
+            &mut self,
+            start: usize,
+            end: usize,
+            f: F,
+        ) -> Result<(), GraphError> {
+            let slot = self
+                .out_edge_slots(start)
+                .find(|&slot| self.edge_at(slot).end == end)
+                .ok_or(GraphError::EdgeNotFound)?;
+            if let Some(edge) = self.edges[slot].as_mut() {
+                f(edge.value.as_mut());
+            }
+            Ok(())
+        }
+    }
+
+    impl<T: FromStr + Debug, V: FromStr + Debug + Clone> DeserializeGraph<T, V> for ArenaGraph<T, V> {
+        type VertexType = ArenaVertex<T, V>;
+        type EdgeType = ArenaEdge<T, V>;
+        type GraphType = ArenaGraph<T, V>;
+
+        fn deserialize_with_policy(
+            graph: &str,
+            policy: GraphPolicy,
+        ) -> Result<Self::GraphType, GraphParseError> {
+            deserialize_into(graph, Self::with_policy(policy))
+        }
+
+        fn deserialize_vertex(vertex: &str) -> Result<Self::VertexType, VertexParseError> {
+            let (id, value) = parse_vertex(vertex)?;
+            Ok(ArenaVertex::new(id, value))
+        }
+
+        fn deserialize_edge(
+            edge: &str,
+            graph: &Self::GraphType,
+        ) -> Result<Self::EdgeType, EdgeParseError> {
+            let (id, start, end, value) = parse_edge(edge, |&id| graph.vertex(id).is_some())?;
+            if graph.vertex(start).is_none() || graph.vertex(end).is_none() {
+                return Err(EdgeParseError::VertexForEdgeIndexNotFound);
+            }
+            let mut edge = ArenaEdge::new(start, end, Some(value));
+            edge.id = id;
+            Ok(edge)
+        }
+    }
+
+    impl<T: ToString, V: ToString + Clone> SerializeGraph<T, V> for ArenaGraph<T, V> {
+        type VertexType = ArenaVertex<T, V>;
+        type EdgeType = ArenaEdge<T, V>;
+        type GraphType = ArenaGraph<T, V>;
+
+        fn serialize(&self) -> Result<String, SerializationError> {
+            serialize_from(self)
+        }
+
+        fn serialize_vertex(vertex: &Self::VertexType) -> String {
+            vertex_line(vertex)
+        }
+
+        fn serialize_edge(edge: &Self::EdgeType) -> Result<String, SerializationError> {
+            edge_line(edge)
+        }
+    }
+}
@@ -0,0 +1,124 @@
+pub mod connectivity {
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::VertexId;
+
+    /// Single points of failure of a graph, ignoring edge direction. Self-loops never
+    /// take part; a pair of parallel edges keeps its ends connected if one of them fails.
+    pub trait Connectivity<T, V, I: VertexId = usize>: GraphView<T, V, I> {
+        /// Ids of the edges whose removal disconnects their ends.
+        fn bridges(&self) -> Vec<usize> {
+            Biconnectivity::new(&Adjacency::new(self)).bridges
+        }
+
+        /// Ids of the vertexes whose removal disconnects some of the others, in the order
+        /// of `vertices()`.
+        fn articulation_points(&self) -> Vec<I> {
+            let adjacency = Adjacency::new(self);
+            Biconnectivity::new(&adjacency)
+                .articulation
+                .into_iter()
+                .zip(adjacency.ids)
+                .filter_map(|(articulation, id)| articulation.then_some(id))
+                .collect()
+        }
+
+        /// Edge ids of every biconnected component: the largest sets of edges in which any
+        /// two lie on a common simple cycle, and single bridges.
+        fn biconnected_components(&self) -> Vec<Vec<usize>> {
+            Biconnectivity::new(&Adjacency::new(self)).components
+        }
+    }
+
+    impl<T, V, I: VertexId, G: GraphView<T, V, I>> Connectivity<T, V, I> for G {}
+
+    /// Result of Tarjan's depth-first search for biconnectivity.
+    struct Biconnectivity {
+        bridges: Vec<usize>,
+        articulation: Vec<bool>,
+        components: Vec<Vec<usize>>,
+    }
+
+    impl Biconnectivity {
+        fn new<I: VertexId>(adjacency: &Adjacency<I>) -> Self {
+            let len = adjacency.len();
+            // Other end and id of every edge at a vertex, self-loops left out.
+            let mut edges: Vec<Vec<(usize, usize)>> = vec![Vec::new(); len];
+            for (start, out) in adjacency.out.iter().enumerate() {
+                for &(end, edge) in out {
+                    if start != end {
+                        edges[start].push((end, edge));
+                        edges[end].push((start, edge));
+                    }
+                }
+            }
+
+            let mut result = Self {
+                bridges: Vec::new(),
+                articulation: vec![false; len],
+                components: Vec::new(),
+            };
+            let mut discovered = vec![usize::MAX; len];
+            let mut low = vec![usize::MAX; len];
+            let mut time = 0;
+            let mut edge_stack = Vec::new();
+            for root in 0..len {
+                if discovered[root] != usize::MAX {
+                    continue;
+                }
+                discovered[root] = time;
+                low[root] = time;
+                time += 1;
+                let mut root_children = 0;
+                // Vertex, the edge it was entered by and the position of the next edge to try.
+                let mut stack = vec![(root, usize::MAX, 0)];
+                while let Some((vertex, parent_edge, position)) = stack.last_mut() {
+                    let (vertex, parent_edge) = (*vertex, *parent_edge);
+                    if let Some(&(next, edge)) = edges[vertex].get(*position) {
+                        *position += 1;
+                        if edge == parent_edge {
+                            continue;
+                        }
+                        if discovered[next] == usize::MAX {
+                            discovered[next] = time;
+                            low[next] = time;
+                            time += 1;
+                            edge_stack.push(edge);
+                            stack.push((next, edge, 0));
+                            if vertex == root {
+                                root_children += 1;
+                            }
+                        } else if discovered[next] < discovered[vertex] {
+                            low[vertex] = low[vertex].min(discovered[next]);
+                            edge_stack.push(edge);
+                        }
+                        continue;
+                    }
+
+                    stack.pop();
+                    let Some(&(parent, _, _)) = stack.last() else {
+                        continue;
+                    };
+                    low[parent] = low[parent].min(low[vertex]);
+                    if low[vertex] > discovered[parent] {
+                        result.bridges.push(parent_edge);
+                    }
+                    if low[vertex] >= discovered[parent] {
+                        if parent != root {
+                            result.articulation[parent] = true;
+                        }
+                        let split = edge_stack
+                            .iter()
+                            .rposition(|&edge| edge == parent_edge)
+                            .unwrap_or_default();
+                        result.components.push(edge_stack.split_off(split));
+                    }
+                }
+                if root_children > 1 {
+                    result.articulation[root] = true;
+                }
+            }
+            result
+        }
+    }
+}
@@ -0,0 +1,312 @@
+pub mod csr {
+    use std::collections::{BTreeMap, HashMap};
+    use std::fmt::Debug;
+    use std::marker::PhantomData;
+
+    use crate::arena::arena::ArenaGraph;
+    use crate::edge::edge::{DefaultEdge, DefaultOrientedEdge};
+    use crate::error::SerializationError;
+    use crate::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
+    use crate::serde::serde_graph::{edge_line, serialize_from, vertex_line, SerializeGraph};
+    use crate::vertex::vertex::{DefaultVertex, IdAllocator, VertexId};
+
+    #[derive(Debug, Clone)]
+    pub struct CsrVertex<T, V, I = usize> {
+        id: I,
+        value: T,
+        /// Ids of every edge that starts or ends in this vertex.
+        edges: Box<[usize]>,
+        edge_value: PhantomData<fn() -> V>,
+    }
+
+    impl<T, V, I: Clone> DefaultVertex<T, V, I> for CsrVertex<T, V, I> {
+        fn id(&self) -> I {
+            self.id.clone()
+        }
+
+        fn value(&self) -> &T {
+            &self.value
+        }
+
+        fn value_mut(&mut self) -> &mut T {
+            &mut self.value
+        }
+
+        fn set_value(&mut self, value: T) {
+            self.value = value
+        }
+
+        fn edge_ids(&self) -> impl Iterator<Item = usize> {
+            self.edges.iter().copied()
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct CsrEdge<T, V, I = usize> {
+        id: usize,
+        start: I,
+        end: I,
+        value: Option<V>,
+        vertex_value: PhantomData<fn() -> T>,
+    }
+
+    impl<T, V, I: Clone> DefaultEdge<T, V, I> for CsrEdge<T, V, I> {
+        fn id(&self) -> Option<usize> {
+            Some(self.id)
+        }
+
+        fn end_id(&self) -> Option<I> {
+            Some(self.end.clone())
+        }
+
+        fn value(&self) -> Option<&V> {
+            self.value.as_ref()
+        }
+
+        fn value_mut(&mut self) -> Option<&mut V> {
+            self.value.as_mut()
+        }
+    }
+
+    impl<T, V, I: Clone> DefaultOrientedEdge<T, V, I> for CsrEdge<T, V, I> {
+        fn start_id(&self) -> Option<I> {
+            Some(self.start.clone())
+        }
+    }
+
+    /// Immutable graph in compressed sparse row form.
+    ///
+    /// Vertexes are renumbered to dense indexes `0..vertex_count()` in the order of the
+    /// source graph. The edges starting in the vertex with index `i` occupy positions
+    /// `offsets()[i]..offsets()[i + 1]` of `targets()`, which holds the dense index of
+    /// each edge end, and of the edge array returned by `out_edges_at`.
+    #[derive(Debug, Clone)]
+    pub struct CsrGraph<T, V, I = usize> {
+        vertices: Vec<CsrVertex<T, V, I>>,
+        index: HashMap<I, usize>,
+        offsets: Vec<usize>,
+        targets: Vec<usize>,
+        edges: Vec<CsrEdge<T, V, I>>,
+        edge_positions: BTreeMap<usize, usize>,
+        policy: GraphPolicy,
+        next_edge_id: usize,
+        vertex_ids: IdAllocator,
+    }
+
+    impl<T: Clone, V: Clone, I: VertexId> CsrGraph<T, V, I> {
+        fn from_view<G: GraphView<T, V, I>>(
+            graph: &G,
+            policy: GraphPolicy,
+            next_edge_id: usize,
+            vertex_ids: IdAllocator,
+        ) -> Self {
+            let mut vertices = Vec::with_capacity(graph.vertex_count());
+            let mut index = HashMap::with_capacity(graph.vertex_count());
+            for vertex in graph.vertices() {
+                index.insert(vertex.id(), vertices.len());
+                vertices.push(CsrVertex {
+                    id: vertex.id(),
+                    value: vertex.value().clone(),
+                    edges: vertex.edge_ids().collect(),
+                    edge_value: PhantomData,
+                });
+            }
+
+            let mut offsets = Vec::with_capacity(vertices.len() + 1);
+            let mut targets = Vec::with_capacity(graph.edges_count());
+            let mut edges = Vec::with_capacity(graph.edges_count());
+            let mut edge_positions = BTreeMap::new();
+            offsets.push(0);
+            for vertex in &vertices {
+                for edge in graph.out_edges(vertex.id.clone()) {
+                    if let (Some(id), Some(end)) = (edge.id(), edge.end_id()) {
+                        edge_positions.insert(id, edges.len());
+                        targets.push(index[&end]);
+                        edges.push(CsrEdge {
+                            id,
+                            start: vertex.id.clone(),
+                            end,
+                            value: edge.value().cloned(),
+                            vertex_value: PhantomData,
+                        });
+                    }
+                }
+                offsets.push(edges.len());
+            }
+
+            Self {
+                vertices,
+                index,
+                offsets,
+                targets,
+                edges,
+                edge_positions,
+                policy,
+                next_edge_id,
+                vertex_ids,
+            }
+        }
+    }
+
+    impl<T, V, I: VertexId> CsrGraph<T, V, I> {
+        pub fn policy(&self) -> GraphPolicy {
+            self.policy
+        }
+
+        /// Dense index of the vertex with the given id.
+        pub fn index_of(&self, id: &I) -> Option<usize> {
+            self.index.get(id).copied()
+        }
+
+        /// Id of the vertex with the given dense index.
+        pub fn id_of(&self, index: usize) -> &I {
+            &self.vertices[index].id
+        }
+
+        pub fn offsets(&self) -> &[usize] {
+            &self.offsets
+        }
+
+        pub fn targets(&self) -> &[usize] {
+            &self.targets
+        }
+
+        /// Edges starting in the vertex with the given dense index.
+        pub fn out_edges_at(&self, index: usize) -> &[CsrEdge<T, V, I>] {
+            &self.edges[self.offsets[index]..self.offsets[index + 1]]
+        }
+
+        fn out_range(&self, id: &I) -> std::ops::Range<usize> {
+            self.index_of(id)
+                .map_or(0..0, |index| self.offsets[index]..self.offsets[index + 1])
+        }
+    }
+
+    impl<T: Debug, V: Debug + Clone, I: VertexId> CsrGraph<T, V, I> {
+        /// Converts the graph back into a mutable one, keeping vertex and edge ids.
+        pub fn thaw(self) -> OrientedGraph<T, V, I> {
+            let mut graph = OrientedGraph::with_policy(self.policy);
+            for vertex in self.vertices {
+                graph
+                    .add_raw_vertex(vertex.id, vertex.value)
+                    .expect("vertex ids of a frozen graph are unique");
+            }
+            for edge in self.edges {
+                graph
+                    .insert_edge(edge.id, edge.start, edge.end, edge.value)
+                    .expect("edges of a frozen graph satisfy its policy");
+            }
+            graph.restore_ids(self.next_edge_id, self.vertex_ids);
+            graph
+        }
+    }
+
+    impl<T: Debug + Clone, V: Debug + Clone, I: VertexId> OrientedGraph<T, V, I> {
+        /// Copies the graph into an immutable [`CsrGraph`].
+        pub fn freeze(&self) -> CsrGraph<T, V, I> {
+            CsrGraph::from_view(
+                self,
+                self.policy(),
+                self.next_edge_id(),
+                self.vertex_ids().clone(),
+            )
+        }
+    }
+
+    impl<T: Clone, V: Clone> ArenaGraph<T, V> {
+        /// Copies the graph into an immutable [`CsrGraph`].
+        pub fn freeze(&self) -> CsrGraph<T, V> {
+            CsrGraph::from_view(
+                self,
+                self.policy(),
+                self.next_edge_id(),
+                self.vertex_ids().clone(),
+            )
+        }
+    }
+
+    impl<T, V, I: VertexId> GraphView<T, V, I> for CsrGraph<T, V, I> {
+        type VertexType = CsrVertex<T, V, I>;
+        type EdgeType = CsrEdge<T, V, I>;
+        type VertexRef<'a>
+            = &'a CsrVertex<T, V, I>
+        where
+            Self: 'a;
+        type EdgeRef<'a>
+            = &'a CsrEdge<T, V, I>
+        where
+            Self: 'a;
+
+        fn vertex_count(&self) -> usize {
+            self.vertices.len()
+        }
+
+        fn edges_count(&self) -> usize {
+            self.edges.len()
+        }
+
+        fn vertices(&self) -> impl Iterator<Item = Self::VertexRef<'_>> {
+            self.vertices.iter()
+        }
+
+        fn edges(&self) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.edge_positions
+                .values()
+                .map(|&position| &self.edges[position])
+        }
+
+        fn edge_ids(&self) -> impl Iterator<Item = usize> {
+            self.edge_positions.keys().copied()
+        }
+
+        fn neighbors(&self, id: I) -> impl Iterator<Item = I> {
+            self.targets[self.out_range(&id)]
+                .iter()
+                .map(|&index| self.vertices[index].id.clone())
+        }
+
+        fn out_edges(&self, id: I) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.edges[self.out_range(&id)].iter()
+        }
+
+        fn get_vertex_by_id(&self, id: I) -> Option<Self::VertexRef<'_>> {
+            self.index_of(&id).map(|index| &self.vertices[index])
+        }
+
+        fn get_edge_by_id(&self, id: usize) -> Option<Self::EdgeRef<'_>> {
+            self.edge_positions
+                .get(&id)
+                .map(|&position| &self.edges[position])
+        }
+
+        fn get_edge_by_vertexes_id(&self, start: I, end: I) -> Option<Self::EdgeRef<'_>> {
+            self.get_edges_by_vertexes_id(start, end).next()
+        }
+
+        fn get_edges_by_vertexes_id(
+            &self,
+            start: I,
+            end: I,
+        ) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.out_edges(start).filter(move |edge| edge.end == end)
+        }
+    }
+
+    impl<T: ToString, V: ToString, I: VertexId> SerializeGraph<T, V, I> for CsrGraph<T, V, I> {
+        type VertexType = CsrVertex<T, V, I>;
+        type EdgeType = CsrEdge<T, V, I>;
+        type GraphType = CsrGraph<T, V, I>;
+
+        fn serialize(&self) -> Result<String, SerializationError> {
+            serialize_from(self)
+        }
+
+        fn serialize_vertex(vertex: &Self::VertexType) -> String {
+            vertex_line(vertex)
+        }
+
+        fn serialize_edge(edge: &Self::EdgeType) -> Result<String, SerializationError> {
+            edge_line(edge)
+        }
+    }
+}
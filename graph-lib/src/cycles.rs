@@ -0,0 +1,191 @@
+pub mod cycles {
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::VertexId;
+
+    /// Directed cycle detection. A cycle is reported as the sequence of its vertex ids;
+    /// an edge leads from each vertex to the next one and from the last back to the first.
+    pub trait Cycles<T, V, I: VertexId = usize>: GraphView<T, V, I> {
+        /// Some directed cycle of the graph, or `None` if the graph is acyclic.
+        fn find_cycle(&self) -> Option<Vec<I>> {
+            let adjacency = Adjacency::new(self);
+            let successors = adjacency.successors();
+            // 0 - not visited, 1 - on the current path, 2 - finished.
+            let mut state = vec![0u8; adjacency.len()];
+            for root in 0..adjacency.len() {
+                if state[root] != 0 {
+                    continue;
+                }
+                state[root] = 1;
+                let mut path = vec![(root, 0)];
+                while let Some((vertex, position)) = path.last_mut() {
+                    let vertex = *vertex;
+                    let Some(&next) = successors[vertex].get(*position) else {
+                        state[vertex] = 2;
+                        path.pop();
+                        continue;
+                    };
+                    *position += 1;
+                    match state[next] {
+                        0 => {
+                            state[next] = 1;
+                            path.push((next, 0));
+                        }
+                        1 => {
+                            let start = path.iter().position(|&(v, _)| v == next)?;
+                            return Some(
+                                path[start..]
+                                    .iter()
+                                    .map(|&(v, _)| adjacency.ids[v].clone())
+                                    .collect(),
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            None
+        }
+
+        /// Every elementary cycle of the graph (Johnson's algorithm), at most `limit` of them.
+        /// Each cycle starts at its vertex that comes first in `vertices()`.
+        fn all_elementary_cycles(&self, limit: usize) -> Vec<Vec<I>> {
+            let adjacency = Adjacency::new(self);
+            let successors = adjacency.successors();
+            let mut johnson = Johnson::new(&successors);
+            for start in 0..adjacency.len() {
+                if johnson.cycles.len() >= limit {
+                    break;
+                }
+                johnson.circuits(start, limit);
+            }
+            johnson
+                .cycles
+                .into_iter()
+                .map(|cycle| {
+                    cycle
+                        .into_iter()
+                        .map(|v| adjacency.ids[v].clone())
+                        .collect()
+                })
+                .collect()
+        }
+    }
+
+    impl<T, V, I: VertexId, G: GraphView<T, V, I>> Cycles<T, V, I> for G {}
+
+    struct Johnson<'a> {
+        successors: &'a [Vec<usize>],
+        predecessors: Vec<Vec<usize>>,
+        component: Vec<bool>,
+        blocked: Vec<bool>,
+        blocked_by: Vec<Vec<usize>>,
+        cycles: Vec<Vec<usize>>,
+    }
+
+    impl<'a> Johnson<'a> {
+        fn new(successors: &'a [Vec<usize>]) -> Self {
+            let mut predecessors = vec![Vec::new(); successors.len()];
+            for (vertex, next) in successors.iter().enumerate() {
+                for &next in next {
+                    predecessors[next].push(vertex);
+                }
+            }
+            Self {
+                successors,
+                predecessors,
+                component: vec![false; successors.len()],
+                blocked: vec![false; successors.len()],
+                blocked_by: vec![Vec::new(); successors.len()],
+                cycles: Vec::new(),
+            }
+        }
+
+        /// Marks the strongly connected component of `start` in the subgraph induced
+        /// by the vertexes not less than `start`.
+        fn mark_component(&mut self, start: usize) {
+            let len = self.successors.len();
+            let mut forward = vec![false; len];
+            let mut stack = vec![start];
+            forward[start] = true;
+            while let Some(vertex) = stack.pop() {
+                for &next in &self.successors[vertex] {
+                    if next >= start && !forward[next] {
+                        forward[next] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+
+            self.component = vec![false; len];
+            self.component[start] = true;
+            stack.push(start);
+            while let Some(vertex) = stack.pop() {
+                for &previous in &self.predecessors[vertex] {
+                    if forward[previous] && !self.component[previous] {
+                        self.component[previous] = true;
+                        stack.push(previous);
+                    }
+                }
+            }
+        }
+
+        fn unblock(&mut self, vertex: usize) {
+            let mut stack = vec![vertex];
+            while let Some(vertex) = stack.pop() {
+                if std::mem::take(&mut self.blocked[vertex]) {
+                    stack.append(&mut self.blocked_by[vertex]);
+                }
+            }
+        }
+
+        /// Finds the cycles through `start` that use no vertex less than `start`.
+        fn circuits(&mut self, start: usize, limit: usize) {
+            self.mark_component(start);
+            for vertex in 0..self.successors.len() {
+                if self.component[vertex] {
+                    self.blocked[vertex] = false;
+                    self.blocked_by[vertex].clear();
+                }
+            }
+
+            let successors = self.successors;
+            self.blocked[start] = true;
+            // Current path: vertex, position in its successors, whether a cycle was found below.
+            let mut path = vec![(start, 0, false)];
+            while let Some(&(vertex, position, found)) = path.last() {
+                if let Some(&next) = successors[vertex].get(position) {
+                    path.last_mut().expect("path is not empty").1 += 1;
+                    if !self.component[next] {
+                        continue;
+                    }
+                    if next == start {
+                        self.cycles.push(path.iter().map(|&(v, _, _)| v).collect());
+                        path.last_mut().expect("path is not empty").2 = true;
+                        if self.cycles.len() >= limit {
+                            return;
+                        }
+                    } else if !self.blocked[next] {
+                        self.blocked[next] = true;
+                        path.push((next, 0, false));
+                    }
+                    continue;
+                }
+
+                if found {
+                    self.unblock(vertex);
+                } else {
+                    for &next in &successors[vertex] {
+                        if self.component[next] && !self.blocked_by[next].contains(&vertex) {
+                            self.blocked_by[next].push(vertex);
+                        }
+                    }
+                }
+                path.pop();
+                if let Some(parent) = path.last_mut() {
+                    parent.2 |= found;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+pub mod dominators {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::error::GraphError;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::VertexId;
+
+    /// Dominator tree of the vertexes reachable from a root. A vertex dominates another
+    /// one if every path from the root to the latter goes through it.
+    #[derive(Debug, Clone)]
+    pub struct Dominators<I> {
+        root: I,
+        immediate: HashMap<I, I>,
+        frontiers: HashMap<I, HashSet<I>>,
+    }
+
+    impl<I: VertexId> Dominators<I> {
+        pub fn root(&self) -> &I {
+            &self.root
+        }
+
+        /// Closest strict dominator of `vertex`, `None` for the root and for vertexes
+        /// the root doesn't reach.
+        pub fn immediate_dominator(&self, vertex: &I) -> Option<&I> {
+            self.immediate.get(vertex)
+        }
+
+        /// Immediate dominator of every reachable vertex but the root.
+        pub fn immediate_dominators(&self) -> &HashMap<I, I> {
+            &self.immediate
+        }
+
+        /// Vertexes where the dominance of `vertex` ends: those with a predecessor that
+        /// `vertex` dominates, while not being strictly dominated by it themselves.
+        pub fn dominance_frontier(&self, vertex: &I) -> Option<&HashSet<I>> {
+            self.frontiers.get(vertex)
+        }
+
+        /// Dominators of `vertex` from the vertex itself up to the root, or `None` if
+        /// the root doesn't reach it.
+        pub fn dominators_of(&self, vertex: &I) -> Option<Vec<I>> {
+            if *vertex != self.root && !self.immediate.contains_key(vertex) {
+                return None;
+            }
+            let mut dominators = vec![vertex.clone()];
+            while let Some(next) = self.immediate.get(dominators.last()?) {
+                dominators.push(next.clone());
+            }
+            Some(dominators)
+        }
+
+        pub fn dominates(&self, dominator: &I, vertex: &I) -> bool {
+            self.dominators_of(vertex)
+                .is_some_and(|dominators| dominators.contains(dominator))
+        }
+    }
+
+    /// Dominance analysis of flow graphs.
+    pub trait Dominance<T, V, I: VertexId = usize>: GraphView<T, V, I> {
+        /// Dominator tree and dominance frontiers of the vertexes reachable from `root`
+        /// (Lengauer–Tarjan).
+        fn dominators(&self, root: I) -> Result<Dominators<I>, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let root = adjacency.index_of(&root)?;
+            let successors = adjacency.successors();
+            let immediate = LengauerTarjan::new(&successors, root).immediate_dominators();
+
+            let len = adjacency.len();
+            let mut frontiers = vec![HashSet::new(); len];
+            for (start, next) in successors.iter().enumerate() {
+                if start != root && immediate[start] == usize::MAX {
+                    continue;
+                }
+                for &end in next {
+                    // Every dominator of `start` not strictly dominating `end` has it on
+                    // its frontier. The root strictly dominates nothing outside itself.
+                    let mut runner = start;
+                    while runner != immediate[end] {
+                        frontiers[runner].insert(end);
+                        if runner == root {
+                            break;
+                        }
+                        runner = immediate[runner];
+                    }
+                }
+            }
+
+            let id = |vertex: usize| adjacency.ids[vertex].clone();
+            Ok(Dominators {
+                root: id(root),
+                immediate: (0..len)
+                    .filter(|&vertex| immediate[vertex] != usize::MAX)
+                    .map(|vertex| (id(vertex), id(immediate[vertex])))
+                    .collect(),
+                frontiers: (0..len)
+                    .filter(|&vertex| vertex == root || immediate[vertex] != usize::MAX)
+                    .map(|vertex| {
+                        let frontier = frontiers[vertex].iter().map(|&end| id(end)).collect();
+                        (id(vertex), frontier)
+                    })
+                    .collect(),
+            })
+        }
+    }
+
+    impl<T, V, I: VertexId, G: GraphView<T, V, I>> Dominance<T, V, I> for G {}
+
+    /// State of the Lengauer–Tarjan algorithm with simple path compression. Vertexes are
+    /// referred to by index, their depth-first preorder numbers by `number`.
+    struct LengauerTarjan {
+        /// Vertex with every preorder number.
+        order: Vec<usize>,
+        number: Vec<usize>,
+        parent: Vec<usize>,
+        predecessors: Vec<Vec<usize>>,
+        /// Preorder number of the semidominator of every vertex.
+        semi: Vec<usize>,
+        ancestor: Vec<usize>,
+        /// Vertex with the least semidominator on the compressed path to the ancestor.
+        label: Vec<usize>,
+    }
+
+    impl LengauerTarjan {
+        fn new(successors: &[Vec<usize>], root: usize) -> Self {
+            let len = successors.len();
+            let mut order = vec![root];
+            let mut number = vec![usize::MAX; len];
+            let mut parent = vec![usize::MAX; len];
+            number[root] = 0;
+            let mut stack = vec![(root, 0)];
+            while let Some((vertex, position)) = stack.last_mut() {
+                let vertex = *vertex;
+                let Some(&next) = successors[vertex].get(*position) else {
+                    stack.pop();
+                    continue;
+                };
+                *position += 1;
+                if number[next] == usize::MAX {
+                    number[next] = order.len();
+                    order.push(next);
+                    parent[next] = vertex;
+                    stack.push((next, 0));
+                }
+            }
+
+            let mut predecessors = vec![Vec::new(); len];
+            for &vertex in &order {
+                for &next in &successors[vertex] {
+                    predecessors[next].push(vertex);
+                }
+            }
+            Self {
+                semi: number.clone(),
+                order,
+                number,
+                parent,
+                predecessors,
+                ancestor: vec![usize::MAX; len],
+                label: (0..len).collect(),
+            }
+        }
+
+        /// Immediate dominator of every vertex, `usize::MAX` for the root and for
+        /// unreachable vertexes.
+        fn immediate_dominators(mut self) -> Vec<usize> {
+            let len = self.number.len();
+            let mut immediate = vec![usize::MAX; len];
+            let mut bucket = vec![Vec::new(); len];
+            for index in (1..self.order.len()).rev() {
+                let vertex = self.order[index];
+                for previous in std::mem::take(&mut self.predecessors[vertex]) {
+                    let least = self.eval(previous);
+                    self.semi[vertex] = self.semi[vertex].min(self.semi[least]);
+                }
+                bucket[self.order[self.semi[vertex]]].push(vertex);
+                let parent = self.parent[vertex];
+                self.ancestor[vertex] = parent;
+                for dominated in std::mem::take(&mut bucket[parent]) {
+                    let least = self.eval(dominated);
+                    immediate[dominated] = if self.semi[least] < self.semi[dominated] {
+                        least
+                    } else {
+                        parent
+                    };
+                }
+            }
+            for index in 1..self.order.len() {
+                let vertex = self.order[index];
+                if immediate[vertex] != self.order[self.semi[vertex]] {
+                    immediate[vertex] = immediate[immediate[vertex]];
+                }
+            }
+            immediate
+        }
+
+        fn eval(&mut self, vertex: usize) -> usize {
+            if self.ancestor[vertex] == usize::MAX {
+                return vertex;
+            }
+            // Compress the path to the topmost linked ancestor, starting from its top.
+            let mut path = Vec::new();
+            let mut next = vertex;
+            while self.ancestor[self.ancestor[next]] != usize::MAX {
+                path.push(next);
+                next = self.ancestor[next];
+            }
+            for &next in path.iter().rev() {
+                let ancestor = self.ancestor[next];
+                if self.semi[self.label[ancestor]] < self.semi[self.label[next]] {
+                    self.label[next] = self.label[ancestor];
+                }
+                self.ancestor[next] = self.ancestor[ancestor];
+            }
+            self.label[vertex]
+        }
+    }
+}
@@ -3,33 +3,31 @@ pub mod edge {
     use std::fmt::Debug;
     use std::rc::{Rc, Weak};
 
-    use crate::vertex::vertex::{DefaultVertex, Vertex};
+    use crate::vertex::vertex::{DefaultVertex, Vertex, VertexId};
 
-    pub trait DefaultEdge<T, V> {
-        type VertexType: DefaultVertex<T, V>;
-        fn end(&self) -> Option<Rc<RefCell<Self::VertexType>>>;
-        fn end_id(&self) -> Option<usize>;
-        fn set_end(&mut self, vertex: &Rc<RefCell<Self::VertexType>>);
+    pub trait DefaultEdge<T, V, I = usize> {
+        fn id(&self) -> Option<usize>;
+        fn end_id(&self) -> Option<I>;
 
         fn value(&self) -> Option<&V>;
         fn value_mut(&mut self) -> Option<&mut V>;
     }
 
-    pub trait DefaultOrientedEdge<T, V>: DefaultEdge<T, V> {
-        fn start(&self) -> Option<Rc<RefCell<Self::VertexType>>>;
-        fn start_id(&self) -> Option<usize>;
-        fn set_start(&mut self, vertex: &Rc<RefCell<Self::VertexType>>);
+    pub trait DefaultOrientedEdge<T, V, I = usize>: DefaultEdge<T, V, I> {
+        fn start_id(&self) -> Option<I>;
     }
     #[derive(Debug)]
-    pub struct OrientedEdge<T: Debug, V: Debug> {
-        start: Weak<RefCell<Vertex<T, V>>>,
-        end: Weak<RefCell<Vertex<T, V>>>,
+    pub struct OrientedEdge<T: Debug, V: Debug, I: VertexId = usize> {
+        id: Option<usize>,
+        start: Weak<RefCell<Vertex<T, V, I>>>,
+        end: Weak<RefCell<Vertex<T, V, I>>>,
         value: Option<V>,
     }
 
-    impl<T: Debug, V: Debug> Default for OrientedEdge<T, V> {
+    impl<T: Debug, V: Debug, I: VertexId> Default for OrientedEdge<T, V, I> {
         fn default() -> Self {
             Self {
+                id: None,
                 start: Weak::default(),
                 end: Weak::default(),
                 value: None,
@@ -37,15 +35,16 @@ pub mod edge {
         }
     }
 
-    impl<T: Debug, V: Debug> OrientedEdge<T, V> {
+    impl<T: Debug, V: Debug, I: VertexId> OrientedEdge<T, V, I> {
         pub(crate) fn new(
-            start: &Rc<RefCell<Vertex<T, V>>>,
-            end: &Rc<RefCell<Vertex<T, V>>>,
+            start: &Rc<RefCell<Vertex<T, V, I>>>,
+            end: &Rc<RefCell<Vertex<T, V, I>>>,
             value: V,
         ) -> Self {
             Self {
-                start: Rc::downgrade(&start),
-                end: Rc::downgrade(&end),
+                id: None,
+                start: Rc::downgrade(start),
+                end: Rc::downgrade(end),
                 value: Some(value),
             }
         }
@@ -56,22 +55,36 @@ pub mod edge {
                 ..Self::default()
             }
         }
-    }
 
-    impl<T: Debug, V: Debug> DefaultEdge<T, V> for OrientedEdge<T, V> {
-        type VertexType = Vertex<T, V>;
+        pub(crate) fn set_id(&mut self, id: usize) {
+            self.id = Some(id)
+        }
 
-        fn end(&self) -> Option<Rc<RefCell<Self::VertexType>>> {
-            self.end.upgrade()
+        pub fn start(&self) -> Option<Rc<RefCell<Vertex<T, V, I>>>> {
+            self.start.upgrade()
         }
 
-        fn end_id(&self) -> Option<usize> {
-            self.end.upgrade().map(|val| val.borrow().id())
+        pub(crate) fn set_start(&mut self, vertex: &Rc<RefCell<Vertex<T, V, I>>>) {
+            self.start = Rc::downgrade(vertex);
         }
 
-        fn set_end(&mut self, vertex: &Rc<RefCell<Self::VertexType>>) {
+        pub fn end(&self) -> Option<Rc<RefCell<Vertex<T, V, I>>>> {
+            self.end.upgrade()
+        }
+
+        pub(crate) fn set_end(&mut self, vertex: &Rc<RefCell<Vertex<T, V, I>>>) {
             self.end = Rc::downgrade(vertex)
         }
+    }
+
+    impl<T: Debug, V: Debug, I: VertexId> DefaultEdge<T, V, I> for OrientedEdge<T, V, I> {
+        fn id(&self) -> Option<usize> {
+            self.id
+        }
+
+        fn end_id(&self) -> Option<I> {
+            self.end.upgrade().map(|val| val.borrow().id())
+        }
 
         fn value(&self) -> Option<&V> {
             Option::from(&self.value)
@@ -82,17 +95,9 @@ pub mod edge {
         }
     }
 
-    impl<T: Debug, V: Debug> DefaultOrientedEdge<T, V> for OrientedEdge<T, V> {
-        fn start(&self) -> Option<Rc<RefCell<Self::VertexType>>> {
-            self.start.upgrade()
-        }
-
-        fn start_id(&self) -> Option<usize> {
+    impl<T: Debug, V: Debug, I: VertexId> DefaultOrientedEdge<T, V, I> for OrientedEdge<T, V, I> {
+        fn start_id(&self) -> Option<I> {
             self.start.upgrade().map(|val| val.borrow().id())
         }
-
-        fn set_start(&mut self, vertex: &Rc<RefCell<Self::VertexType>>) {
-            self.start = Rc::downgrade(vertex);
-        }
     }
 }
@@ -1,6 +1,7 @@
 use thiserror::Error;
 
 #[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum VertexParseError {
     #[error("Failed to parse vertex")]
     VertexParsingError,
@@ -22,14 +23,19 @@ pub enum EdgeParseError {
     VertexForEdgeIndexNotFound,
     #[error("Failed to parse edge value")]
     EdgeValueParsingError,
+    #[error("Failed to parse edge id")]
+    EdgeIdParsingError,
 }
 
 #[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum GraphParseError {
     #[error("Failed to parse index")]
     VertexParsingError,
     #[error("Failed to parse value")]
     EdgeParsingError,
+    #[error("Edge violates graph policy: {0}")]
+    EdgePolicyError(GraphError),
 }
 
 #[derive(Error, Debug)]
@@ -46,6 +52,24 @@ pub enum GraphError {
     EdgeExistsError,
     #[error("Vertex already exists")]
     VertexExistsError,
+    #[error("No vertex ids are left")]
+    VertexIdsExhaustedError,
+    #[error("No edge ids are left")]
+    EdgeIdsExhaustedError,
+    #[error("Self-loops are forbidden by graph policy")]
+    SelfLoopError,
+    #[error("Edge weight is negative")]
+    NegativeWeightError,
+    #[error("Graph contains a cycle of negative weight")]
+    NegativeCycleError,
+    #[error("Graph contains a cycle")]
+    CycleError,
+    #[error("Graph is not bipartite")]
+    NotBipartiteError,
+    #[error("No assignment covers every vertex of the smaller side")]
+    InfeasibleAssignmentError,
+    #[error("Source and sink are the same vertex")]
+    SameSourceAndSinkError,
 }
 
 #[derive(Error, Debug)]
@@ -0,0 +1,425 @@
+pub mod flow {
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+    use std::ops::{Mul, Neg};
+
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::analytics::analytics::bellman_ford;
+    use crate::edge::edge::DefaultEdge;
+    use crate::error::GraphError;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::VertexId;
+    use crate::weight::weight::{Ordered, Weight};
+
+    /// Maximum flow between two vertexes together with a minimum cut.
+    #[derive(Debug, Clone)]
+    pub struct MaxFlow<I, W> {
+        pub value: W,
+        /// Flow through every edge, keyed by edge id.
+        pub edge_flows: HashMap<usize, W>,
+        /// Vertexes still reachable from the source in the residual network.
+        pub source_side: HashSet<I>,
+        pub sink_side: HashSet<I>,
+        /// Ids of the saturated edges leading from the source side to the sink side.
+        pub cut_edges: Vec<usize>,
+    }
+
+    /// Maximum flow of the lowest total cost.
+    #[derive(Debug, Clone)]
+    pub struct MinCostFlow<C, W> {
+        pub value: C,
+        /// Sum of flow times cost over every edge.
+        pub cost: W,
+        /// Flow through every edge, keyed by edge id.
+        pub edge_flows: HashMap<usize, C>,
+    }
+
+    /// Maximum flow algorithms. Edge values are turned into capacities by `capacity`;
+    /// edges without a value have no capacity, and self-loops never carry flow.
+    pub trait Flow<T, V, I: VertexId = usize>: GraphView<T, V, I> {
+        /// Edmonds–Karp: augments along shortest paths found by breadth-first search.
+        fn edmonds_karp<W: Weight, F: Fn(&V) -> W>(
+            &self,
+            source: I,
+            sink: I,
+            capacity: F,
+        ) -> Result<MaxFlow<I, W>, GraphError> {
+            let (adjacency, mut residual, source, sink) =
+                Residual::build(self, source, sink, capacity)?;
+            let mut value = W::default();
+            while let Some(path) = residual.shortest_path(source, sink) {
+                value = value + residual.augment(&path);
+            }
+            Ok(residual.into_max_flow(&adjacency, source, value))
+        }
+
+        /// Dinic: augments along blocking flows of the breadth-first level graph.
+        fn dinic<W: Weight, F: Fn(&V) -> W>(
+            &self,
+            source: I,
+            sink: I,
+            capacity: F,
+        ) -> Result<MaxFlow<I, W>, GraphError> {
+            let (adjacency, mut residual, source, sink) =
+                Residual::build(self, source, sink, capacity)?;
+            let mut value = W::default();
+            while let Some(levels) = residual.levels(source, sink) {
+                let mut current = vec![0; adjacency.len()];
+                while let Some(path) = residual.level_path(&levels, &mut current, source, sink) {
+                    value = value + residual.augment(&path);
+                }
+            }
+            Ok(residual.into_max_flow(&adjacency, source, value))
+        }
+
+        /// Maximum flow of the lowest total cost, found by successive shortest paths with
+        /// vertex potentials. `cost` gives the cost of a unit of flow through an edge; edges
+        /// without a value cost nothing. The cost type must be signed, as cancelling flow
+        /// has negative cost, and hold every capacity. Costs may be negative as long as no
+        /// cycle of negative cost can carry flow.
+        fn min_cost_max_flow<C, W, F, K>(
+            &self,
+            source: I,
+            sink: I,
+            capacity: F,
+            cost: K,
+        ) -> Result<MinCostFlow<C, W>, GraphError>
+        where
+            C: Weight + Into<W>,
+            W: Weight + Mul<Output = W> + Neg<Output = W>,
+            F: Fn(&V) -> C,
+            K: Fn(&V) -> W,
+        {
+            let (adjacency, mut residual, source, sink) =
+                Residual::build(self, source, sink, capacity)?;
+            let costs: Vec<W> = residual
+                .edges
+                .iter()
+                .flat_map(|&edge| {
+                    let cost = self
+                        .get_edge_by_id(edge)
+                        .and_then(|edge| edge.value().map(&cost))
+                        .unwrap_or_default();
+                    [cost, -cost]
+                })
+                .collect();
+            let mut potentials = residual.initial_potentials(&costs, source)?;
+            let mut value = C::default();
+            while let Some(path) = residual.cheapest_path(&costs, &mut potentials, source, sink) {
+                value = value + residual.augment(&path);
+            }
+            let total = (0..residual.edges.len()).fold(W::default(), |total, pair| {
+                total + residual.flow(pair).into() * costs[2 * pair]
+            });
+            Ok(MinCostFlow {
+                value,
+                cost: total,
+                edge_flows: residual.edge_flows(&adjacency),
+            })
+        }
+    }
+
+    impl<T, V, I: VertexId, G: GraphView<T, V, I>> Flow<T, V, I> for G {}
+
+    /// Residual network. Every edge of the graph is the forward arc `2 * k`, paired with
+    /// the reverse arc `2 * k + 1`, whose residual capacity is the flow through the edge.
+    pub(crate) struct Residual<W> {
+        /// Arcs leaving every vertex.
+        pub(crate) arcs: Vec<Vec<usize>>,
+        pub(crate) heads: Vec<usize>,
+        pub(crate) capacities: Vec<W>,
+        /// Id of the edge behind every pair of arcs.
+        pub(crate) edges: Vec<usize>,
+    }
+
+    impl<W: Weight> Residual<W> {
+        pub(crate) fn new<T, V, I, G, F>(adjacency: &Adjacency<I>, graph: &G, capacity: F) -> Self
+        where
+            I: VertexId,
+            G: GraphView<T, V, I> + ?Sized,
+            F: Fn(&V) -> W,
+        {
+            let mut residual = Self {
+                arcs: vec![Vec::new(); adjacency.len()],
+                heads: Vec::new(),
+                capacities: Vec::new(),
+                edges: Vec::new(),
+            };
+            let weighted = adjacency.weighted(graph, capacity);
+            for (start, (weighted, out)) in weighted.into_iter().zip(&adjacency.out).enumerate() {
+                for ((end, capacity), &(_, edge)) in weighted.into_iter().zip(out) {
+                    if start != end {
+                        residual.push(start, end, capacity, edge);
+                    }
+                }
+            }
+            residual
+        }
+
+        fn build<T, V, I, G, F>(
+            graph: &G,
+            source: I,
+            sink: I,
+            capacity: F,
+        ) -> Result<(Adjacency<I>, Self, usize, usize), GraphError>
+        where
+            I: VertexId,
+            G: GraphView<T, V, I> + ?Sized,
+            F: Fn(&V) -> W,
+        {
+            let adjacency = Adjacency::new(graph);
+            let source = adjacency.index_of(&source)?;
+            let sink = adjacency.index_of(&sink)?;
+            if source == sink {
+                return Err(GraphError::SameSourceAndSinkError);
+            }
+            let residual = Self::new(&adjacency, graph, capacity);
+            if residual
+                .capacities
+                .iter()
+                .any(|&capacity| capacity < W::default())
+            {
+                return Err(GraphError::NegativeWeightError);
+            }
+            Ok((adjacency, residual, source, sink))
+        }
+
+        fn push(&mut self, start: usize, end: usize, capacity: W, edge: usize) {
+            self.arcs[start].push(self.heads.len());
+            self.heads.push(end);
+            self.capacities.push(capacity);
+            self.arcs[end].push(self.heads.len());
+            self.heads.push(start);
+            self.capacities.push(W::default());
+            self.edges.push(edge);
+        }
+
+        pub(crate) fn has_capacity(&self, arc: usize) -> bool {
+            self.capacities[arc] > W::default()
+        }
+
+        /// Flow through the edge behind the arc pair `pair`.
+        pub(crate) fn flow(&self, pair: usize) -> W {
+            self.capacities[2 * pair + 1]
+        }
+
+        /// Pushes the bottleneck capacity of the path of arcs and returns it.
+        pub(crate) fn augment(&mut self, path: &[usize]) -> W {
+            let mut bottleneck = self.capacities[path[0]];
+            for &arc in &path[1..] {
+                if self.capacities[arc] < bottleneck {
+                    bottleneck = self.capacities[arc];
+                }
+            }
+            for &arc in path {
+                self.capacities[arc] = self.capacities[arc] - bottleneck;
+                self.capacities[arc ^ 1] = self.capacities[arc ^ 1] + bottleneck;
+            }
+            bottleneck
+        }
+
+        /// Arcs of a path with the fewest arcs from `source` to `sink`.
+        fn shortest_path(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+            let mut parent_arc = vec![usize::MAX; self.arcs.len()];
+            let mut queue = VecDeque::from([source]);
+            while let Some(vertex) = queue.pop_front() {
+                for &arc in &self.arcs[vertex] {
+                    let next = self.heads[arc];
+                    if next != source && parent_arc[next] == usize::MAX && self.has_capacity(arc) {
+                        parent_arc[next] = arc;
+                        if next == sink {
+                            return Some(self.path_to(sink, &parent_arc));
+                        }
+                        queue.push_back(next);
+                    }
+                }
+            }
+            None
+        }
+
+        /// Follows `parent_arc` back from `end` to a vertex without a parent arc.
+        pub(crate) fn path_to(&self, end: usize, parent_arc: &[usize]) -> Vec<usize> {
+            let mut path = Vec::new();
+            let mut vertex = end;
+            while parent_arc[vertex] != usize::MAX {
+                path.push(parent_arc[vertex]);
+                vertex = self.heads[parent_arc[vertex] ^ 1];
+            }
+            path.reverse();
+            path
+        }
+
+        /// Breadth-first distance from `source` in the residual network, or `None`
+        /// if `sink` can't be reached.
+        fn levels(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+            let mut levels = vec![usize::MAX; self.arcs.len()];
+            levels[source] = 0;
+            let mut queue = VecDeque::from([source]);
+            while let Some(vertex) = queue.pop_front() {
+                for &arc in &self.arcs[vertex] {
+                    let next = self.heads[arc];
+                    if levels[next] == usize::MAX && self.has_capacity(arc) {
+                        levels[next] = levels[vertex] + 1;
+                        queue.push_back(next);
+                    }
+                }
+            }
+            (levels[sink] != usize::MAX).then_some(levels)
+        }
+
+        /// Next path of the level graph, skipping arcs that `current` marks as exhausted.
+        fn level_path(
+            &self,
+            levels: &[usize],
+            current: &mut [usize],
+            source: usize,
+            sink: usize,
+        ) -> Option<Vec<usize>> {
+            let mut path: Vec<usize> = Vec::new();
+            let mut vertex = source;
+            while vertex != sink {
+                let arcs = &self.arcs[vertex];
+                while current[vertex] < arcs.len() {
+                    let arc = arcs[current[vertex]];
+                    if self.has_capacity(arc) && levels[self.heads[arc]] == levels[vertex] + 1 {
+                        break;
+                    }
+                    current[vertex] += 1;
+                }
+                if current[vertex] < arcs.len() {
+                    let arc = arcs[current[vertex]];
+                    path.push(arc);
+                    vertex = self.heads[arc];
+                } else {
+                    // Dead end: retreat and never enter this vertex again.
+                    let arc = path.pop()?;
+                    vertex = self.heads[arc ^ 1];
+                    current[vertex] += 1;
+                }
+            }
+            Some(path)
+        }
+
+        /// Vertexes reachable from `source` through arcs with residual capacity.
+        pub(crate) fn reachable(&self, source: usize) -> Vec<bool> {
+            let mut reached = vec![false; self.arcs.len()];
+            reached[source] = true;
+            let mut stack = vec![source];
+            while let Some(vertex) = stack.pop() {
+                for &arc in &self.arcs[vertex] {
+                    let next = self.heads[arc];
+                    if !reached[next] && self.has_capacity(arc) {
+                        reached[next] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+            reached
+        }
+
+        /// Costs of the shortest paths from `source` over arcs with residual capacity,
+        /// which make every reduced arc cost non-negative.
+        fn initial_potentials<K: Weight>(
+            &self,
+            costs: &[K],
+            source: usize,
+        ) -> Result<Vec<K>, GraphError> {
+            let weighted: Vec<Vec<(usize, K)>> = self
+                .arcs
+                .iter()
+                .map(|arcs| {
+                    arcs.iter()
+                        .filter(|&&arc| self.has_capacity(arc))
+                        .map(|&arc| (self.heads[arc], costs[arc]))
+                        .collect()
+                })
+                .collect();
+            Ok(bellman_ford(&weighted, source)?
+                .into_iter()
+                .map(Option::unwrap_or_default)
+                .collect())
+        }
+
+        /// Arcs of the cheapest path from `source` to `sink` (Dijkstra on reduced costs).
+        /// Moves the potentials of the reached vertexes by their distance.
+        fn cheapest_path<K: Weight>(
+            &self,
+            costs: &[K],
+            potentials: &mut [K],
+            source: usize,
+            sink: usize,
+        ) -> Option<Vec<usize>> {
+            let mut lengths: Vec<Option<K>> = vec![None; self.arcs.len()];
+            let mut parent_arc = vec![usize::MAX; self.arcs.len()];
+            let mut done = vec![false; self.arcs.len()];
+            let mut heap = BinaryHeap::from([Reverse((Ordered(K::default()), source))]);
+            lengths[source] = Some(K::default());
+            while let Some(Reverse((Ordered(length), vertex))) = heap.pop() {
+                if std::mem::replace(&mut done[vertex], true) {
+                    continue;
+                }
+                for &arc in &self.arcs[vertex] {
+                    let next = self.heads[arc];
+                    if next == source || !self.has_capacity(arc) {
+                        continue;
+                    }
+                    let candidate = length + costs[arc] + potentials[vertex] - potentials[next];
+                    if lengths[next].is_none_or(|known| candidate < known) {
+                        lengths[next] = Some(candidate);
+                        parent_arc[next] = arc;
+                        heap.push(Reverse((Ordered(candidate), next)));
+                    }
+                }
+            }
+            lengths[sink]?;
+            for (potential, length) in potentials.iter_mut().zip(lengths) {
+                if let Some(length) = length {
+                    *potential = *potential + length;
+                }
+            }
+            Some(self.path_to(sink, &parent_arc))
+        }
+
+        /// Flow through every edge of the graph, keyed by edge id.
+        fn edge_flows<I: VertexId>(&self, adjacency: &Adjacency<I>) -> HashMap<usize, W> {
+            let mut edge_flows: HashMap<usize, W> = adjacency
+                .out
+                .iter()
+                .flatten()
+                .map(|&(_, edge)| (edge, W::default()))
+                .collect();
+            for (pair, &edge) in self.edges.iter().enumerate() {
+                edge_flows.insert(edge, self.flow(pair));
+            }
+            edge_flows
+        }
+
+        fn into_max_flow<I: VertexId>(
+            self,
+            adjacency: &Adjacency<I>,
+            source: usize,
+            value: W,
+        ) -> MaxFlow<I, W> {
+            let reached = self.reachable(source);
+            let mut cut_edges = Vec::new();
+            for (pair, &edge) in self.edges.iter().enumerate() {
+                if reached[self.heads[2 * pair + 1]] && !reached[self.heads[2 * pair]] {
+                    cut_edges.push(edge);
+                }
+            }
+            let (source_side, sink_side) = adjacency
+                .ids
+                .iter()
+                .cloned()
+                .zip(reached)
+                .partition::<Vec<_>, _>(|&(_, reached)| reached);
+            MaxFlow {
+                value,
+                edge_flows: self.edge_flows(adjacency),
+                source_side: source_side.into_iter().map(|(id, _)| id).collect(),
+                sink_side: sink_side.into_iter().map(|(id, _)| id).collect(),
+                cut_edges,
+            }
+        }
+    }
+}
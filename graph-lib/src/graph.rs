@@ -1,252 +1,459 @@
 pub mod graph {
-    use std::cell::RefCell;
-    use std::collections::HashMap;
+    use std::cell::{Ref, RefCell};
+    use std::collections::{BTreeMap, HashMap};
     use std::fmt::{Debug, Display};
+    use std::ops::Deref;
     use std::rc::Rc;
 
     use crate::edge::edge::{DefaultEdge, DefaultOrientedEdge, OrientedEdge};
     use crate::error::GraphError;
-    use crate::vertex::vertex::{DefaultVertex, Vertex};
+    use crate::vertex::vertex::{DefaultVertex, IdAllocator, SequentialId, Vertex, VertexId};
+
+    /// Read-only access to a graph. Implemented by frozen representations as well,
+    /// so algorithms that only look at the graph are written against this trait.
+    pub trait GraphView<T, V, I = usize> {
+        type VertexType: DefaultVertex<T, V, I>;
+        type EdgeType: DefaultOrientedEdge<T, V, I>;
+        type VertexRef<'a>: Deref<Target = Self::VertexType>
+        where
+            Self: 'a;
+        type EdgeRef<'a>: Deref<Target = Self::EdgeType>
+        where
+            Self: 'a;
 
-    pub trait DefaultGraph<T, V> {
-        type VertexType: DefaultVertex<T, V>;
-        type EdgeType: DefaultEdge<T, V>;
         fn vertex_count(&self) -> usize;
         fn edges_count(&self) -> usize;
-        fn get_vertexes(&self) -> Vec<Rc<RefCell<Self::VertexType>>>;
-        fn get_edges(&self) -> Vec<Rc<RefCell<Self::EdgeType>>>;
-        fn get_vertex_by_id(&mut self, id: usize) -> Option<Rc<RefCell<Self::VertexType>>>;
-        fn get_edge_by_vertexes_id(
+        fn vertices(&self) -> impl Iterator<Item = Self::VertexRef<'_>>;
+        fn edges(&self) -> impl Iterator<Item = Self::EdgeRef<'_>>;
+        fn edge_ids(&self) -> impl Iterator<Item = usize>;
+        /// Ids of the vertexes reachable from `id` by one outgoing edge.
+        fn neighbors(&self, id: I) -> impl Iterator<Item = I>;
+        fn out_edges(&self, id: I) -> impl Iterator<Item = Self::EdgeRef<'_>>;
+        fn get_vertex_by_id(&self, id: I) -> Option<Self::VertexRef<'_>>;
+        fn get_edge_by_id(&self, id: usize) -> Option<Self::EdgeRef<'_>>;
+        fn get_edge_by_vertexes_id(&self, start: I, end: I) -> Option<Self::EdgeRef<'_>>;
+        fn get_edges_by_vertexes_id(
             &self,
-            start: usize,
-            end: usize,
-        ) -> Option<Rc<RefCell<Self::EdgeType>>>;
-        fn add_edge(&mut self, edge: Self::EdgeType) -> Result<(), GraphError>;
+            start: I,
+            end: I,
+        ) -> impl Iterator<Item = Self::EdgeRef<'_>>;
+    }
+
+    pub trait DefaultGraph<T, V, I = usize>: GraphView<T, V, I> {
+        fn add_edge(&mut self, edge: Self::EdgeType) -> Result<usize, GraphError>;
         fn add_edge_with_vertex_id(
             &mut self,
-            start: usize,
-            end: usize,
+            start: I,
+            end: I,
             value: Option<V>,
-        ) -> Result<(), GraphError>;
+        ) -> Result<usize, GraphError>;
 
-        fn remove_edge(&mut self, edge: &Rc<RefCell<Self::EdgeType>>) -> Result<(), GraphError>;
+        fn remove_edge_by_id(&mut self, id: usize) -> Result<(), GraphError>;
 
         fn remove_edge_by_vertexes(
             &mut self,
-            start_vertex_id: usize,
-            end_vertex_id: usize,
+            start_vertex_id: I,
+            end_vertex_id: I,
         ) -> Result<(), GraphError>;
 
         fn add_vertex(&mut self, vertex: Self::VertexType) -> Result<(), GraphError>;
-        fn add_raw_vertex(&mut self, id: usize, value: T) -> Result<(), GraphError>;
-        fn remove_vertex_by_id(&mut self, id: usize) -> Result<(), GraphError>;
+        fn add_raw_vertex(&mut self, id: I, value: T) -> Result<(), GraphError>;
+        fn insert_vertex(&mut self, value: T) -> Result<I, GraphError>
+        where
+            I: SequentialId;
+        fn remove_vertex_by_id(&mut self, id: I) -> Result<(), GraphError>;
+
+        fn update_vertex<F: FnOnce(&mut T)>(&mut self, id: I, f: F) -> Result<(), GraphError>;
+        fn update_edge<F: FnOnce(Option<&mut V>)>(
+            &mut self,
+            start: I,
+            end: I,
+            f: F,
+        ) -> Result<(), GraphError>;
+    }
+
+    /// Rules a graph is constructed with. Every edge insertion is checked against them.
+    /// The default allows self-loops, forbids parallel edges and never hands out
+    /// an id of a removed vertex again. A second edge between the same vertexes is
+    /// rejected with `EdgeExistsError` whenever parallel edges are forbidden.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct GraphPolicy {
+        pub self_loops: bool,
+        pub parallel_edges: bool,
+        pub reuse_vertex_ids: bool,
+    }
+
+    impl Default for GraphPolicy {
+        fn default() -> Self {
+            Self {
+                self_loops: true,
+                parallel_edges: false,
+                reuse_vertex_ids: false,
+            }
+        }
+    }
+
+    impl GraphPolicy {
+        /// Neither self-loops nor parallel edges are allowed.
+        pub fn simple() -> Self {
+            Self {
+                self_loops: false,
+                parallel_edges: false,
+                reuse_vertex_ids: false,
+            }
+        }
+
+        /// Both self-loops and parallel edges are allowed.
+        pub fn multigraph() -> Self {
+            Self {
+                self_loops: true,
+                parallel_edges: true,
+                reuse_vertex_ids: false,
+            }
+        }
+
+        pub(crate) fn check<I: PartialEq>(
+            &self,
+            start: &I,
+            end: &I,
+            exists: bool,
+        ) -> Result<(), GraphError> {
+            if !self.self_loops && start == end {
+                return Err(GraphError::SelfLoopError);
+            }
+            if !self.parallel_edges && exists {
+                return Err(GraphError::EdgeExistsError);
+            }
+            Ok(())
+        }
     }
 
+    pub(crate) type VertexRc<T, V, I> = Rc<RefCell<Vertex<T, V, I>>>;
+    pub(crate) type EdgeRc<T, V, I> = Rc<RefCell<OrientedEdge<T, V, I>>>;
+
     #[derive(Debug)]
-    pub struct OrientedGraph<T: Debug, V: Debug> {
-        vertexes: Vec<Rc<RefCell<Vertex<T, V>>>>,
-        edges: Vec<Rc<RefCell<OrientedEdge<T, V>>>>,
+    pub struct OrientedGraph<T: Debug, V: Debug, I: VertexId = usize> {
+        vertexes: Vec<VertexRc<T, V, I>>,
+        vertex_index: HashMap<I, VertexRc<T, V, I>>,
+        edges: BTreeMap<usize, EdgeRc<T, V, I>>,
+        next_edge_id: usize,
+        vertex_ids: IdAllocator,
+        policy: GraphPolicy,
     }
 
-    impl<T: Debug, V: Debug> Default for OrientedGraph<T, V> {
+    impl<T: Debug, V: Debug, I: VertexId> Default for OrientedGraph<T, V, I> {
         fn default() -> Self {
+            Self::with_policy(GraphPolicy::default())
+        }
+    }
+
+    /// Walks the edge list of one vertex and yields the edges starting in it.
+    struct OutEdges<'a, T: Debug, V: Debug, I: VertexId> {
+        edges: &'a BTreeMap<usize, EdgeRc<T, V, I>>,
+        vertex: Option<Ref<'a, Vertex<T, V, I>>>,
+        position: usize,
+    }
+
+    impl<'a, T: Debug, V: Debug, I: VertexId> Iterator for OutEdges<'a, T, V, I> {
+        type Item = Ref<'a, OrientedEdge<T, V, I>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let vertex = self.vertex.as_ref()?;
+            let edges = self.edges;
+            while let Some(edge) = vertex.edges.get(self.position) {
+                self.position += 1;
+                let edge = edge.borrow();
+                if edge.start_id() == Some(vertex.id()) {
+                    if let Some(edge) = edge.id().and_then(|id| edges.get(&id)) {
+                        return Some(edge.borrow());
+                    }
+                }
+            }
+            None
+        }
+    }
+
+    impl<T: Debug, V: Debug, I: VertexId> OrientedGraph<T, V, I> {
+        pub fn with_policy(policy: GraphPolicy) -> Self {
             Self {
                 vertexes: Vec::new(),
-                edges: Vec::new(),
+                vertex_index: HashMap::new(),
+                edges: BTreeMap::new(),
+                next_edge_id: 0,
+                vertex_ids: IdAllocator::default(),
+                policy,
             }
         }
-    }
 
-    impl<T: Debug, V: Debug + Clone> DefaultGraph<T, V> for OrientedGraph<T, V> {
-        type VertexType = Vertex<T, V>;
-        type EdgeType = OrientedEdge<T, V>;
+        /// Creates a graph that accepts several edges between the same ordered pair of vertexes.
+        /// Such edges are told apart by the id returned from `add_edge_with_vertex_id`.
+        pub fn multigraph() -> Self {
+            Self::with_policy(GraphPolicy::multigraph())
+        }
 
-        fn vertex_count(&self) -> usize {
-            self.vertexes.len()
+        pub fn policy(&self) -> GraphPolicy {
+            self.policy
         }
 
-        fn edges_count(&self) -> usize {
-            self.edges.len()
+        pub(crate) fn next_edge_id(&self) -> usize {
+            self.next_edge_id
         }
 
-        fn get_vertexes(&self) -> Vec<Rc<RefCell<Self::VertexType>>> {
-            self.vertexes.clone()
+        pub(crate) fn vertex_ids(&self) -> &IdAllocator {
+            &self.vertex_ids
         }
 
-        fn get_edges(&self) -> Vec<Rc<RefCell<Self::EdgeType>>> {
-            self.edges.clone()
+        /// Replaces the id bookkeeping, so ids removed before a copy aren't handed out again.
+        pub(crate) fn restore_ids(&mut self, next_edge_id: usize, vertex_ids: IdAllocator) {
+            self.next_edge_id = next_edge_id;
+            self.vertex_ids = vertex_ids;
         }
 
-        fn get_vertex_by_id(&mut self, id: usize) -> Option<Rc<RefCell<Self::VertexType>>> {
-            self.vertexes
-                .iter()
-                .find(|&p| p.borrow().id() == id)
-                .cloned()
+        pub(crate) fn vertex_rc(&self, id: &I) -> Option<&VertexRc<T, V, I>> {
+            self.vertex_index.get(id)
         }
 
-        fn get_edge_by_vertexes_id(
-            &self,
-            start: usize,
-            end: usize,
-        ) -> Option<Rc<RefCell<Self::EdgeType>>> {
-            self.edges
-                .iter()
-                .find(|p| p.borrow().start_id() == Some(start) && p.borrow().end_id() == Some(end))
-                .cloned()
+        fn out_edge_refs(&self, id: &I) -> OutEdges<'_, T, V, I> {
+            OutEdges {
+                edges: &self.edges,
+                vertex: self.vertex_index.get(id).map(|vertex| vertex.borrow()),
+                position: 0,
+            }
         }
 
-        fn add_edge(&mut self, edge: Self::EdgeType) -> Result<(), GraphError> {
-            if let (Some(start), Some(end)) = (edge.start_id(), edge.end_id()) {
-                if self.get_edge_by_vertexes_id(start, end).is_some() {
-                    return Err(GraphError::EdgeExistsError);
-                }
+        fn reserve_vertex_id(&mut self, id: &I) {
+            if let Some(index) = id.index() {
+                self.vertex_ids.reserve(index);
+            }
+        }
+
+        fn push_vertex(&mut self, vertex: Vertex<T, V, I>) -> Result<(), GraphError> {
+            let id = vertex.id();
+            if self.vertex_index.contains_key(&id) {
+                return Err(GraphError::VertexExistsError);
             }
-            self.add_edge_with_vertex_id(
-                edge.start_id().unwrap(),
-                edge.end_id().unwrap(),
-                edge.value().cloned(),
-            )?;
+            self.reserve_vertex_id(&id);
+            let vertex = Rc::new(RefCell::new(vertex));
+            self.vertex_index.insert(id, vertex.clone());
+            self.vertexes.push(vertex);
             Ok(())
         }
 
-        fn add_edge_with_vertex_id(
+        pub(crate) fn insert_edge(
             &mut self,
-            start: usize,
-            end: usize,
+            id: usize,
+            start: I,
+            end: I,
             value: Option<V>,
-        ) -> Result<(), GraphError> {
-            if self.get_edge_by_vertexes_id(start, end).is_some() {
-                return Err(GraphError::EdgeExistsError);
-            }
-            let edge = Rc::new(RefCell::new(OrientedEdge::<T, V>::new_with_value(value)));
+        ) -> Result<usize, GraphError> {
+            let next_edge_id = id.checked_add(1).ok_or(GraphError::EdgeIdsExhaustedError)?;
+            let exists = self
+                .out_edge_refs(&start)
+                .any(|edge| edge.end_id().as_ref() == Some(&end));
+            self.policy.check(&start, &end, exists)?;
 
             if let (Some(start), Some(end)) =
-                (self.get_vertex_by_id(start), self.get_vertex_by_id(end))
+                (self.vertex_index.get(&start), self.vertex_index.get(&end))
             {
-                edge.borrow_mut().set_start(&start);
-                edge.borrow_mut().set_end(&end);
+                let edge = Rc::new(RefCell::new(OrientedEdge::<T, V, I>::new_with_value(value)));
+                edge.borrow_mut().set_id(id);
+                edge.borrow_mut().set_start(start);
+                edge.borrow_mut().set_end(end);
 
                 start.borrow_mut().add_neighbor(edge.clone());
-                end.borrow_mut().add_neighbor(edge.clone());
-                self.edges.push(edge);
-                return Ok(());
+                if !Rc::ptr_eq(start, end) {
+                    end.borrow_mut().add_neighbor(edge.clone());
+                }
+                self.edges.insert(id, edge);
+                self.next_edge_id = self.next_edge_id.max(next_edge_id);
+                return Ok(id);
             }
             Err(GraphError::VertexNotFound)
         }
 
-        fn remove_edge(
-            &mut self,
-            edge: &Rc<RefCell<OrientedEdge<T, V>>>,
-        ) -> Result<(), GraphError> {
-            self.edges.retain(|e| !Rc::ptr_eq(e, edge));
-
-            if let (Some(start), Some(end)) = (edge.borrow().start(), edge.borrow().end()) {
-                start
-                    .borrow_mut()
-                    .get_edges()
-                    .retain(|e| !Rc::ptr_eq(&e, edge));
-                end.borrow_mut()
-                    .get_edges()
-                    .retain(|e| !Rc::ptr_eq(&e, edge));
-                return Ok(());
+        fn detach_edge(&mut self, id: usize) -> Result<(), GraphError> {
+            let edge = self.edges.remove(&id).ok_or(GraphError::EdgeNotFound)?;
+            let (start, end) = {
+                let borrow = edge.borrow();
+                (borrow.start(), borrow.end())
+            };
+            if let Some(start) = start {
+                start.borrow_mut().remove_neighbor(&edge);
+            }
+            if let Some(end) = end {
+                end.borrow_mut().remove_neighbor(&edge);
             }
-            Err(GraphError::EdgeRemovingError)
+            Ok(())
+        }
+    }
+
+    impl<T: Debug, V: Debug, I: VertexId> GraphView<T, V, I> for OrientedGraph<T, V, I> {
+        type VertexType = Vertex<T, V, I>;
+        type EdgeType = OrientedEdge<T, V, I>;
+        type VertexRef<'a>
+            = Ref<'a, Vertex<T, V, I>>
+        where
+            Self: 'a;
+        type EdgeRef<'a>
+            = Ref<'a, OrientedEdge<T, V, I>>
+        where
+            Self: 'a;
+
+        fn vertex_count(&self) -> usize {
+            self.vertexes.len()
+        }
+
+        fn edges_count(&self) -> usize {
+            self.edges.len()
+        }
+
+        fn vertices(&self) -> impl Iterator<Item = Self::VertexRef<'_>> {
+            self.vertexes.iter().map(|vertex| vertex.borrow())
+        }
+
+        fn edges(&self) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.edges.values().map(|edge| edge.borrow())
+        }
+
+        fn edge_ids(&self) -> impl Iterator<Item = usize> {
+            self.edges.keys().copied()
+        }
+
+        fn neighbors(&self, id: I) -> impl Iterator<Item = I> {
+            self.out_edge_refs(&id).filter_map(|edge| edge.end_id())
+        }
+
+        fn out_edges(&self, id: I) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.out_edge_refs(&id)
+        }
+
+        fn get_vertex_by_id(&self, id: I) -> Option<Self::VertexRef<'_>> {
+            self.vertex_index.get(&id).map(|vertex| vertex.borrow())
+        }
+
+        fn get_edge_by_id(&self, id: usize) -> Option<Self::EdgeRef<'_>> {
+            self.edges.get(&id).map(|edge| edge.borrow())
+        }
+
+        fn get_edge_by_vertexes_id(&self, start: I, end: I) -> Option<Self::EdgeRef<'_>> {
+            self.get_edges_by_vertexes_id(start, end).next()
+        }
+
+        fn get_edges_by_vertexes_id(
+            &self,
+            start: I,
+            end: I,
+        ) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.out_edge_refs(&start)
+                .filter(move |edge| edge.end_id().as_ref() == Some(&end))
         }
+    }
+
+    impl<T: Debug, V: Debug + Clone, I: VertexId> DefaultGraph<T, V, I> for OrientedGraph<T, V, I> {
+        fn add_edge(&mut self, edge: Self::EdgeType) -> Result<usize, GraphError> {
+            if let (Some(start), Some(end)) = (edge.start_id(), edge.end_id()) {
+                return match edge.id() {
+                    Some(id) if self.edges.contains_key(&id) => Err(GraphError::EdgeExistsError),
+                    Some(id) => self.insert_edge(id, start, end, edge.value().cloned()),
+                    None => self.add_edge_with_vertex_id(start, end, edge.value().cloned()),
+                };
+            }
+            Err(GraphError::VertexNotFound)
+        }
+
+        fn add_edge_with_vertex_id(
+            &mut self,
+            start: I,
+            end: I,
+            value: Option<V>,
+        ) -> Result<usize, GraphError> {
+            self.insert_edge(self.next_edge_id, start, end, value)
+        }
+
+        fn remove_edge_by_id(&mut self, id: usize) -> Result<(), GraphError> {
+            self.detach_edge(id)
+        }
+
         fn remove_edge_by_vertexes(
             &mut self,
-            start_vertex_id: usize,
-            end_vertex_id: usize,
+            start_vertex_id: I,
+            end_vertex_id: I,
         ) -> Result<(), GraphError> {
-            if let (Some(start), Some(end)) = (
-                self.get_vertex_by_id(start_vertex_id),
-                self.get_vertex_by_id(end_vertex_id),
-            ) {
-                let edge_index = start.borrow().get_edges().iter().position(|edge| {
-                    if let Some(end_ref) = edge.borrow().end() {
-                        return end_ref.borrow().id() == end.borrow().id();    
-                    }
-                    false
-                });
+            let id = self
+                .get_edge_by_vertexes_id(start_vertex_id, end_vertex_id)
+                .and_then(|edge| edge.id())
+                .ok_or(GraphError::EdgeNotFound)?;
+            self.detach_edge(id)
+        }
 
-                if let Some(index) = edge_index {
-                    let edge = start.borrow_mut().get_edges().remove(index);
+        fn add_vertex(&mut self, vertex: Self::VertexType) -> Result<(), GraphError> {
+            self.push_vertex(vertex)
+        }
 
-                    self.edges.retain(|e| !Rc::ptr_eq(&e, &edge));
-                    end.borrow_mut()
-                        .get_edges()
-                        .retain(|e| !Rc::ptr_eq(&e, &edge));
+        fn add_raw_vertex(&mut self, id: I, value: T) -> Result<(), GraphError> {
+            self.push_vertex(Vertex::<T, V, I>::new(id, value))
+        }
 
-                    return Ok(());
-                }
-            }
-            Err(GraphError::EdgeNotFound)
+        fn insert_vertex(&mut self, value: T) -> Result<I, GraphError>
+        where
+            I: SequentialId,
+        {
+            let index = self.vertex_ids.next()?;
+            let id = I::from_index(index).ok_or(GraphError::VertexIdsExhaustedError)?;
+            self.push_vertex(Vertex::<T, V, I>::new(id.clone(), value))?;
+            Ok(id)
         }
 
-        fn add_vertex(&mut self, vertex: Self::VertexType) -> Result<(), GraphError> {
-            if self.get_vertex_by_id(vertex.id()).is_some() {
-                return Err(GraphError::VertexExistsError);
+        fn remove_vertex_by_id(&mut self, id: I) -> Result<(), GraphError> {
+            let vertex = self
+                .vertex_index
+                .remove(&id)
+                .ok_or(GraphError::VertexNotFound)?;
+            self.vertexes.retain(|v| !Rc::ptr_eq(v, &vertex));
+            if let Some(index) = id.index().filter(|_| self.policy.reuse_vertex_ids) {
+                self.vertex_ids.release(index);
             }
 
-            self.vertexes.push(Rc::new(RefCell::new(vertex)));
+            let edge_ids: Vec<usize> = vertex.borrow().edge_ids().collect();
+            for edge_id in edge_ids {
+                self.detach_edge(edge_id)?;
+            }
             Ok(())
         }
 
-        fn add_raw_vertex(&mut self, id: usize, value: T) -> Result<(), GraphError> {
-            if self.get_vertex_by_id(id).is_some() {
-                return Err(GraphError::VertexExistsError);
-            }
-            self.vertexes
-                .push(Rc::new(RefCell::new(Vertex::<T, V>::new(id, value))));
+        fn update_vertex<F: FnOnce(&mut T)>(&mut self, id: I, f: F) -> Result<(), GraphError> {
+            let vertex = self
+                .vertex_index
+                .get(&id)
+                .ok_or(GraphError::VertexNotFound)?;
+            f(vertex.borrow_mut().value_mut());
             Ok(())
         }
 
-        fn remove_vertex_by_id(&mut self, id: usize) -> Result<(), GraphError> {
-            let vertex_index = self
-                .vertexes
-                .iter()
-                .position(|vertex| vertex.borrow().id() == id);
-
-            if let Some(index) = vertex_index {
-                let removed_vertex = self.vertexes.remove(index);
-
-                self.edges.retain(|edge| {
-                    if let (Some(start_ptr), Some(end_ptr)) =
-                        (&edge.borrow().start(), &edge.borrow().end())
-                    {
-                        return start_ptr.borrow().id() != removed_vertex.clone().borrow().id()
-                            && end_ptr.borrow().id() != removed_vertex.clone().borrow().id();
-                    };
-                    true
-                });
-
-                for vertex in &self.vertexes {
-                    let edges = &mut vertex.borrow_mut().get_edges();
-                    edges.retain(|edge| {
-                        if let (Some(start_ptr), Some(end_ptr)) =
-                            (&edge.borrow().start(), &edge.borrow().end())
-                        {
-                            return start_ptr.borrow().id() != removed_vertex.clone().borrow().id()
-                                && end_ptr.borrow().id() != removed_vertex.clone().borrow().id();
-                        }
-                        true
-                    });
-                }
-                return Ok(());
-            }
-            Err(GraphError::VertexNotFound)
+        fn update_edge<F: FnOnce(Option<&mut V>)>(
+            &mut self,
+            start: I,
+            end: I,
+            f: F,
+        ) -> Result<(), GraphError> {
+            let id = self
+                .get_edge_by_vertexes_id(start, end)
+                .and_then(|edge| edge.id())
+                .ok_or(GraphError::EdgeNotFound)?;
+            f(self.edges[&id].borrow_mut().value_mut());
+            Ok(())
         }
     }
 
-    pub struct DFSResult<T: Debug, V: Debug>(Vec<Rc<RefCell<Vertex<T, V>>>>);
+    pub struct DFSResult<T: Debug, V: Debug, I: VertexId = usize>(Vec<VertexRc<T, V, I>>);
 
-    impl<T: Debug + ToString, V: Debug + ToString> Display for DFSResult<T, V> {
+    impl<T: Debug + ToString, V: Debug + ToString, I: VertexId + Debug> Display for DFSResult<T, V, I> {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             let mut res = String::new();
-            for vertex in self.0.clone() {
+            for vertex in &self.0 {
                 let borrow = vertex.borrow();
-                let mut adjacent: Vec<usize> = borrow
-                    .get_edges()
-                    .iter()
+                let mut adjacent: Vec<I> = borrow
+                    .edges()
                     .filter_map(|p| {
                         let edge_borrow = p.borrow();
                         if edge_borrow.start_id() != Some(borrow.id()) {
@@ -260,7 +467,7 @@ pub mod graph {
                 res.push_str(
                     format!(
                         "{} {} {:?}\n",
-                        borrow.id(),
+                        borrow.id().to_string(),
                         borrow.value().to_string(),
                         adjacent
                     )
@@ -271,41 +478,36 @@ pub mod graph {
         }
     }
 
-    impl<T: Debug, V: Debug> OrientedGraph<T, V> {
+    impl<T: Debug, V: Debug, I: VertexId> OrientedGraph<T, V, I> {
         pub fn depth_first_search(
             &self,
-            start_vertex_id: usize,
-        ) -> Result<DFSResult<T, V>, GraphError> {
+            start_vertex_id: I,
+        ) -> Result<DFSResult<T, V, I>, GraphError> {
             let mut result = Vec::new();
-            let start_vertex = self
-                .vertexes
-                .iter()
-                .find(|vertex| vertex.borrow().id() == start_vertex_id);
+            let start_vertex = self.vertex_index.get(&start_vertex_id);
 
             if let Some(vertex) = start_vertex {
-                let mut visited: HashMap<usize, bool> = HashMap::new();
+                let mut visited: HashMap<I, bool> = HashMap::new();
                 result.push(vertex.clone());
-                self.dfs_helper(vertex, &mut visited, &mut result);
-                return Ok(DFSResult::<T, V> { 0: result });
+                self.dfs_helper(start_vertex_id, &mut visited, &mut result);
+                return Ok(DFSResult::<T, V, I>(result));
             }
             Err(GraphError::VertexNotFound)
         }
 
         fn dfs_helper(
             &self,
-            vertex: &Rc<RefCell<Vertex<T, V>>>,
-            visited: &mut HashMap<usize, bool>,
-            result: &mut Vec<Rc<RefCell<Vertex<T, V>>>>,
+            vertex_id: I,
+            visited: &mut HashMap<I, bool>,
+            result: &mut Vec<VertexRc<T, V, I>>,
         ) {
-            visited.insert(vertex.borrow().id(), true);
+            visited.insert(vertex_id.clone(), true);
 
-            for edge in &vertex.borrow().get_edges() {
-                let neighbor = edge.borrow().end();
-                if let Some(neighbor) = neighbor {
-                    let neighbor_id = neighbor.borrow().id();
+            for edge in self.out_edge_refs(&vertex_id) {
+                if let Some(neighbor_id) = edge.end_id() {
                     if visited.get(&neighbor_id).is_none() {
-                        result.push(neighbor.clone());
-                        self.dfs_helper(&neighbor, visited, result);
+                        result.push(self.vertex_index[&neighbor_id].clone());
+                        self.dfs_helper(neighbor_id, visited, result);
                     }
                 }
             }
@@ -1,5 +1,25 @@
-mod edge;
-mod error;
+#![allow(clippy::module_inception)]
+
+mod adjacency;
+pub mod analytics;
+pub mod arena;
+pub mod connectivity;
+pub mod csr;
+pub mod cycles;
+pub mod dominators;
+pub mod edge;
+pub mod error;
+pub mod flow;
 pub mod graph;
+pub mod matching;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod paths;
 pub mod serde;
-mod vertex;
+pub mod shared;
+pub mod spanning;
+pub mod transitive;
+pub mod traversal;
+pub mod union_find;
+pub mod vertex;
+pub mod weight;
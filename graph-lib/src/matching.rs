@@ -0,0 +1,355 @@
+pub mod matching {
+    use std::collections::{HashSet, VecDeque};
+    use std::ops::Neg;
+
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::error::GraphError;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::VertexId;
+    use crate::weight::weight::Weight;
+
+    /// Outcome of splitting a graph into two sides with every edge between them.
+    #[derive(Debug, Clone)]
+    pub enum Bipartition<I> {
+        /// The first vertex of every connected component goes to the left side.
+        Sides { left: HashSet<I>, right: HashSet<I> },
+        /// A cycle of odd length, which no bipartite graph has. An edge joins every vertex
+        /// with the next one and the last vertex with the first, in either direction.
+        OddCycle(Vec<I>),
+    }
+
+    /// Assignment of the smaller side of a bipartite graph to the larger one.
+    #[derive(Debug, Clone)]
+    pub struct Assignment<I, W> {
+        /// Left and right vertex of every assigned pair.
+        pub pairs: Vec<(I, I)>,
+        pub cost: W,
+    }
+
+    /// Bipartite graphs and matchings, ignoring edge direction.
+    pub trait Matching<T, V, I: VertexId = usize>: GraphView<T, V, I> {
+        fn bipartition(&self) -> Bipartition<I> {
+            let adjacency = Adjacency::new(self);
+            match two_coloring(&adjacency) {
+                Ok(sides) => {
+                    let (left, right): (Vec<_>, Vec<_>) = adjacency
+                        .ids
+                        .iter()
+                        .cloned()
+                        .zip(sides)
+                        .partition(|&(_, right)| !right);
+                    Bipartition::Sides {
+                        left: left.into_iter().map(|(id, _)| id).collect(),
+                        right: right.into_iter().map(|(id, _)| id).collect(),
+                    }
+                }
+                Err(cycle) => Bipartition::OddCycle(
+                    cycle
+                        .into_iter()
+                        .map(|vertex| adjacency.ids[vertex].clone())
+                        .collect(),
+                ),
+            }
+        }
+
+        /// Maximum matching (Hopcroft–Karp) between the vertexes in `left` and all others.
+        /// Every pair has its left vertex first. Fails with `NotBipartiteError` if an edge
+        /// joins two vertexes of the same side.
+        fn maximum_matching(&self, left: &HashSet<I>) -> Result<Vec<(I, I)>, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let right = sides(&adjacency, left)?;
+            let neighbors: Vec<Vec<usize>> = (0..adjacency.len())
+                .map(|vertex| {
+                    if right[vertex] {
+                        return Vec::new();
+                    }
+                    let mut neighbors: Vec<usize> = adjacency.undirected(vertex).collect();
+                    neighbors.sort_unstable();
+                    neighbors.dedup();
+                    neighbors
+                })
+                .collect();
+            Ok(hopcroft_karp(&neighbors, &right)
+                .into_iter()
+                .enumerate()
+                .filter(|&(vertex, _)| !right[vertex])
+                .filter_map(|(vertex, mate)| {
+                    let mate = mate?;
+                    Some((adjacency.ids[vertex].clone(), adjacency.ids[mate].clone()))
+                })
+                .collect())
+        }
+
+        /// Assignment of lowest total cost that pairs every vertex of the smaller of the
+        /// sides `left` and all other vertexes with a distinct vertex of the other side
+        /// (Hungarian algorithm). Pairs have their left vertex first. Edge values are
+        /// the costs of pairs, with the cheapest one counting between parallel edges;
+        /// pairs without an edge are forbidden. The weight type must be signed. Fails with
+        /// `NotBipartiteError` if an edge joins two vertexes of the same side.
+        fn min_cost_assignment<W, F>(
+            &self,
+            left: &HashSet<I>,
+            cost: F,
+        ) -> Result<Assignment<I, W>, GraphError>
+        where
+            W: Weight + Neg<Output = W>,
+            F: Fn(&V) -> W,
+        {
+            let adjacency = Adjacency::new(self);
+            let right = sides(&adjacency, left)?;
+            let (mut rows, mut columns): (Vec<usize>, Vec<usize>) =
+                (0..adjacency.len()).partition(|&vertex| !right[vertex]);
+            let transposed = rows.len() > columns.len();
+            if transposed {
+                std::mem::swap(&mut rows, &mut columns);
+            }
+            // Position of every vertex among the rows or among the columns.
+            let mut position = vec![0; adjacency.len()];
+            for side in [&rows, &columns] {
+                for (index, &vertex) in side.iter().enumerate() {
+                    position[vertex] = index;
+                }
+            }
+
+            let mut costs: Vec<Vec<Option<W>>> = vec![vec![None; columns.len()]; rows.len()];
+            let weighted = adjacency.weighted(self, cost);
+            for (start, edges) in weighted.into_iter().enumerate() {
+                for (end, cost) in edges {
+                    let (row, column) = if right[start] != transposed {
+                        (position[end], position[start])
+                    } else {
+                        (position[start], position[end])
+                    };
+                    let cell = &mut costs[row][column];
+                    if cell.is_none_or(|known| cost < known) {
+                        *cell = Some(cost);
+                    }
+                }
+            }
+
+            let assigned = hungarian(&costs).ok_or(GraphError::InfeasibleAssignmentError)?;
+            let mut assignment = Assignment {
+                pairs: Vec::with_capacity(rows.len()),
+                cost: W::default(),
+            };
+            for (row, column) in assigned.into_iter().enumerate() {
+                assignment.cost = assignment.cost + costs[row][column].unwrap_or_default();
+                let (row, column) = (
+                    adjacency.ids[rows[row]].clone(),
+                    adjacency.ids[columns[column]].clone(),
+                );
+                assignment.pairs.push(if transposed {
+                    (column, row)
+                } else {
+                    (row, column)
+                });
+            }
+            Ok(assignment)
+        }
+    }
+
+    impl<T, V, I: VertexId, G: GraphView<T, V, I>> Matching<T, V, I> for G {}
+
+    /// Column of every row in the cheapest assignment of the rows to distinct columns,
+    /// given no more rows than columns. `None` costs are forbidden pairs.
+    fn hungarian<W: Weight + Neg<Output = W>>(costs: &[Vec<Option<W>>]) -> Option<Vec<usize>> {
+        let rows = costs.len();
+        let columns = costs.first().map_or(0, Vec::len);
+        // Potentials and the row of every column, with the extra column 0 standing for
+        // the row being added.
+        let mut row_potential = vec![W::default(); rows + 1];
+        let mut column_potential = vec![W::default(); columns + 1];
+        let mut row_of = vec![0; columns + 1];
+        let mut previous = vec![0; columns + 1];
+        for row in 1..=rows {
+            row_of[0] = row;
+            let mut column = 0;
+            let mut slack: Vec<Option<W>> = vec![None; columns + 1];
+            let mut used = vec![false; columns + 1];
+            loop {
+                used[column] = true;
+                let current = row_of[column];
+                let mut delta: Option<W> = None;
+                let mut next = 0;
+                for candidate in 1..=columns {
+                    if used[candidate] {
+                        continue;
+                    }
+                    if let Some(cost) = costs[current - 1][candidate - 1] {
+                        let reduced = cost - row_potential[current] - column_potential[candidate];
+                        if slack[candidate].is_none_or(|known| reduced < known) {
+                            slack[candidate] = Some(reduced);
+                            previous[candidate] = column;
+                        }
+                    }
+                    if let Some(slack) = slack[candidate] {
+                        if delta.is_none_or(|known| slack < known) {
+                            delta = Some(slack);
+                            next = candidate;
+                        }
+                    }
+                }
+                let delta = delta?;
+                for candidate in 0..=columns {
+                    if used[candidate] {
+                        row_potential[row_of[candidate]] = row_potential[row_of[candidate]] + delta;
+                        column_potential[candidate] = column_potential[candidate] - delta;
+                    } else if let Some(slack) = &mut slack[candidate] {
+                        *slack = *slack - delta;
+                    }
+                }
+                column = next;
+                if row_of[column] == 0 {
+                    break;
+                }
+            }
+            while column != 0 {
+                let before = previous[column];
+                row_of[column] = row_of[before];
+                column = before;
+            }
+        }
+
+        let mut assigned = vec![0; rows];
+        for column in 1..=columns {
+            if row_of[column] != 0 {
+                assigned[row_of[column] - 1] = column - 1;
+            }
+        }
+        Some(assigned)
+    }
+
+    /// Whether every vertex is on the right side, given the ids on the left side.
+    fn sides<I: VertexId>(
+        adjacency: &Adjacency<I>,
+        left: &HashSet<I>,
+    ) -> Result<Vec<bool>, GraphError> {
+        let mut right = vec![true; adjacency.len()];
+        for id in left {
+            right[adjacency.index_of(id)?] = false;
+        }
+        for (start, out) in adjacency.out.iter().enumerate() {
+            if out.iter().any(|&(end, _)| right[start] == right[end]) {
+                return Err(GraphError::NotBipartiteError);
+            }
+        }
+        Ok(right)
+    }
+
+    /// Whether every vertex is on the right side, or the vertexes of an odd cycle.
+    fn two_coloring<I: VertexId>(adjacency: &Adjacency<I>) -> Result<Vec<bool>, Vec<usize>> {
+        let mut right = vec![false; adjacency.len()];
+        let mut parent = vec![usize::MAX; adjacency.len()];
+        let mut depth = vec![usize::MAX; adjacency.len()];
+        for root in 0..adjacency.len() {
+            if depth[root] != usize::MAX {
+                continue;
+            }
+            depth[root] = 0;
+            let mut queue = VecDeque::from([root]);
+            while let Some(vertex) = queue.pop_front() {
+                for next in adjacency.undirected(vertex) {
+                    if depth[next] == usize::MAX {
+                        depth[next] = depth[vertex] + 1;
+                        parent[next] = vertex;
+                        right[next] = !right[vertex];
+                        queue.push_back(next);
+                    } else if right[next] == right[vertex] {
+                        return Err(odd_cycle(&parent, &depth, vertex, next));
+                    }
+                }
+            }
+        }
+        Ok(right)
+    }
+
+    /// Closes the tree paths from `first` and `second` to their common ancestor with
+    /// the edge between them.
+    fn odd_cycle(parent: &[usize], depth: &[usize], first: usize, second: usize) -> Vec<usize> {
+        let (mut up, mut down) = (vec![first], vec![second]);
+        let (mut first, mut second) = (first, second);
+        while first != second {
+            if depth[first] >= depth[second] {
+                first = parent[first];
+                up.push(first);
+            } else {
+                second = parent[second];
+                down.push(second);
+            }
+        }
+        // The common ancestor ends both paths.
+        down.pop();
+        up.extend(down.into_iter().rev());
+        up
+    }
+
+    /// Mate of every vertex in a maximum matching between the left vertexes, whose
+    /// `neighbors` are all on the right, and the right ones.
+    fn hopcroft_karp(neighbors: &[Vec<usize>], right: &[bool]) -> Vec<Option<usize>> {
+        let len = neighbors.len();
+        let mut mate: Vec<Option<usize>> = vec![None; len];
+        loop {
+            // Layers of alternating paths starting in free left vertexes, up to the first
+            // layer next to a free right vertex, which ends every shortest augmenting path.
+            let mut layer = vec![usize::MAX; len];
+            let mut queue: VecDeque<usize> = (0..len)
+                .filter(|&vertex| !right[vertex] && mate[vertex].is_none())
+                .collect();
+            for &vertex in &queue {
+                layer[vertex] = 0;
+            }
+            let mut last = usize::MAX;
+            while let Some(vertex) = queue.pop_front() {
+                if layer[vertex] > last {
+                    break;
+                }
+                for &next in &neighbors[vertex] {
+                    match mate[next] {
+                        None => last = layer[vertex],
+                        Some(left) if layer[left] == usize::MAX => {
+                            layer[left] = layer[vertex] + 1;
+                            queue.push_back(left);
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+            if last == usize::MAX {
+                return mate;
+            }
+
+            let mut current = vec![0; len];
+            for free in 0..len {
+                if right[free] || mate[free].is_some() {
+                    continue;
+                }
+                let mut path = vec![free];
+                while let Some(&vertex) = path.last() {
+                    let Some(&next) = neighbors[vertex].get(current[vertex]) else {
+                        // No augmenting path goes through this vertex in this phase.
+                        layer[vertex] = usize::MAX;
+                        path.pop();
+                        if let Some(&previous) = path.last() {
+                            current[previous] += 1;
+                        }
+                        continue;
+                    };
+                    match mate[next] {
+                        None if layer[vertex] == last => {
+                            for &left in &path {
+                                let right = neighbors[left][current[left]];
+                                mate[left] = Some(right);
+                                mate[right] = Some(left);
+                            }
+                            break;
+                        }
+                        Some(left) if layer[left] == layer[vertex] + 1 && layer[left] <= last => {
+                            path.push(left)
+                        }
+                        _ => current[vertex] += 1,
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+pub mod parallel {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use rayon::prelude::*;
+
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::analytics::analytics::{
+        check_non_negative, dijkstra, initial_ranks, lengths_by_id, out_degrees, rank_base, rank_of,
+    };
+    use crate::error::GraphError;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::VertexId;
+    use crate::weight::weight::Weight;
+
+    /// Parallel counterparts of `Traversal` and `Analytics`.
+    ///
+    /// The graph is first copied into a dense read-only snapshot which the worker threads
+    /// share, so any representation can be used. Results are identical to the sequential
+    /// versions.
+    pub trait ParallelAnalytics<T, V, I: VertexId + Send + Sync = usize>:
+        GraphView<T, V, I>
+    {
+        /// Level-synchronous breadth-first search; same order as `breadth_first_order`.
+        fn par_breadth_first_order(&self, start: I) -> Result<Vec<I>, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let start = adjacency.index_of(&start)?;
+            let mut visited = vec![false; adjacency.len()];
+            let mut order = Vec::new();
+            let mut frontier = vec![start];
+            visited[start] = true;
+            while !frontier.is_empty() {
+                order.extend(frontier.iter().map(|&vertex| adjacency.ids[vertex].clone()));
+                let candidates: Vec<Vec<usize>> = frontier
+                    .par_iter()
+                    .map(|&vertex| {
+                        adjacency.out[vertex]
+                            .iter()
+                            .map(|&(next, _)| next)
+                            .filter(|&next| !visited[next])
+                            .collect()
+                    })
+                    .collect();
+                frontier = Vec::new();
+                for next in candidates.into_iter().flatten() {
+                    if !visited[next] {
+                        visited[next] = true;
+                        frontier.push(next);
+                    }
+                }
+            }
+            Ok(order)
+        }
+
+        fn par_page_rank(&self, damping: f64, iterations: usize) -> HashMap<I, f64> {
+            let adjacency = Adjacency::new(self);
+            let mut ranks = initial_ranks(&adjacency);
+            let degrees = out_degrees(&adjacency);
+            for _ in 0..iterations {
+                let base = rank_base(&adjacency, &degrees, &ranks, damping);
+                ranks = (0..adjacency.len())
+                    .into_par_iter()
+                    .map(|vertex| rank_of(&adjacency, &degrees, &ranks, damping, base, vertex))
+                    .collect();
+            }
+            adjacency.by_id(ranks)
+        }
+
+        /// Concurrent union-find over the edges; components are numbered as in the
+        /// sequential version.
+        fn par_weakly_connected_components(&self) -> HashMap<I, usize> {
+            let adjacency = Adjacency::new(self);
+            let parents: Vec<AtomicUsize> = (0..adjacency.len()).map(AtomicUsize::new).collect();
+            (0..adjacency.len()).into_par_iter().for_each(|vertex| {
+                for &(next, _) in &adjacency.out[vertex] {
+                    union(&parents, vertex, next);
+                }
+            });
+
+            let mut numbers = HashMap::new();
+            let components = (0..adjacency.len())
+                .map(|vertex| {
+                    let count = numbers.len();
+                    *numbers.entry(find(&parents, vertex)).or_insert(count)
+                })
+                .collect();
+            adjacency.by_id(components)
+        }
+
+        /// Runs one Dijkstra search per source in parallel.
+        fn par_all_shortest_path_lengths<W, F>(
+            &self,
+            weight: F,
+        ) -> Result<HashMap<I, HashMap<I, W>>, GraphError>
+        where
+            W: Weight + Send + Sync,
+            F: Fn(&V) -> W,
+        {
+            let adjacency = Adjacency::new(self);
+            let weighted = adjacency.weighted(self, weight);
+            check_non_negative(&weighted)?;
+            let lengths: Vec<Vec<Option<W>>> = (0..adjacency.len())
+                .into_par_iter()
+                .map(|source| dijkstra(&weighted, source))
+                .collect();
+            Ok(adjacency
+                .ids
+                .iter()
+                .cloned()
+                .zip(lengths)
+                .map(|(id, lengths)| (id, lengths_by_id(&adjacency, lengths)))
+                .collect())
+        }
+    }
+
+    /// Root of the set containing `element`. Every parent is smaller than its child,
+    /// so concurrent path halving only ever shortens the path to the root.
+    fn find(parents: &[AtomicUsize], mut element: usize) -> usize {
+        loop {
+            let parent = parents[element].load(Ordering::Relaxed);
+            if parent == element {
+                return element;
+            }
+            let grandparent = parents[parent].load(Ordering::Relaxed);
+            let _ = parents[element].compare_exchange(
+                parent,
+                grandparent,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+            element = grandparent;
+        }
+    }
+
+    /// Links the larger of the two roots under the smaller one, retrying if another
+    /// thread linked either root first. The root of a set stays its smallest element.
+    fn union(parents: &[AtomicUsize], first: usize, second: usize) {
+        loop {
+            let (first, second) = (find(parents, first), find(parents, second));
+            if first == second {
+                return;
+            }
+            let (child, root) = (first.max(second), first.min(second));
+            if parents[child]
+                .compare_exchange(child, root, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    impl<T, V, I, G> ParallelAnalytics<T, V, I> for G
+    where
+        I: VertexId + Send + Sync,
+        G: GraphView<T, V, I>,
+    {
+    }
+}
@@ -0,0 +1,428 @@
+pub mod paths {
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashMap, HashSet};
+
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::analytics::analytics::{bellman_ford, check_non_negative, dijkstra_tree};
+    use crate::edge::edge::DefaultEdge;
+    use crate::error::GraphError;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::{DefaultVertex, VertexId};
+    use crate::weight::weight::{Ordered, Weight};
+
+    /// Path through the graph with its total weight.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Path<I, W> {
+        /// Ids of the vertexes along the path, both ends included.
+        pub vertices: Vec<I>,
+        pub cost: W,
+    }
+
+    /// Shortest paths between every pair of vertexes.
+    #[derive(Debug, Clone)]
+    pub struct AllPairs<I, W> {
+        ids: Vec<I>,
+        index: HashMap<I, usize>,
+        /// Length of the shortest path between every pair of dense indexes.
+        lengths: Vec<Vec<Option<W>>>,
+        /// Vertex before the end on the shortest path between every pair.
+        parents: Vec<Vec<usize>>,
+    }
+
+    impl<I: VertexId, W: Weight> AllPairs<I, W> {
+        fn new(
+            adjacency: Adjacency<I>,
+            lengths: Vec<Vec<Option<W>>>,
+            parents: Vec<Vec<usize>>,
+        ) -> Self {
+            Self {
+                ids: adjacency.ids,
+                index: adjacency.index,
+                lengths,
+                parents,
+            }
+        }
+
+        /// Length of the shortest path from `start` to `end`, or `None` if there is none.
+        pub fn distance(&self, start: &I, end: &I) -> Option<W> {
+            self.lengths[*self.index.get(start)?][*self.index.get(end)?]
+        }
+
+        pub fn path(&self, start: &I, end: &I) -> Option<Path<I, W>> {
+            let (start, end) = (*self.index.get(start)?, *self.index.get(end)?);
+            let cost = self.lengths[start][end]?;
+            let mut vertices = vec![self.ids[end].clone()];
+            let mut vertex = end;
+            while vertex != start {
+                vertex = self.parents[start][vertex];
+                vertices.push(self.ids[vertex].clone());
+            }
+            vertices.reverse();
+            Some(Path { vertices, cost })
+        }
+
+        /// Lengths of the shortest paths, keyed by start and end id.
+        pub fn distances(&self) -> HashMap<I, HashMap<I, W>> {
+            self.ids
+                .iter()
+                .zip(&self.lengths)
+                .map(|(start, lengths)| {
+                    let lengths = self
+                        .ids
+                        .iter()
+                        .zip(lengths)
+                        .filter_map(|(end, &length)| Some((end.clone(), length?)))
+                        .collect();
+                    (start.clone(), lengths)
+                })
+                .collect()
+        }
+
+        /// Greatest distance from `vertex` to another vertex, or `None` if some vertex
+        /// can't be reached from it.
+        pub fn eccentricity(&self, vertex: &I) -> Option<W> {
+            self.eccentricity_at(*self.index.get(vertex)?)
+        }
+
+        fn eccentricity_at(&self, vertex: usize) -> Option<W> {
+            self.lengths[vertex]
+                .iter()
+                .try_fold(W::default(), |greatest, &length| {
+                    let length = length?;
+                    Some(if length > greatest { length } else { greatest })
+                })
+        }
+
+        /// Greatest eccentricity, or `None` if some vertex can't reach every other one.
+        pub fn diameter(&self) -> Option<W> {
+            (0..self.ids.len()).try_fold(None, |greatest: Option<W>, vertex| {
+                let eccentricity = self.eccentricity_at(vertex)?;
+                Some(Some(match greatest {
+                    Some(greatest) if greatest >= eccentricity => greatest,
+                    _ => eccentricity,
+                }))
+            })?
+        }
+
+        /// Least eccentricity among the vertexes that reach every other one.
+        pub fn radius(&self) -> Option<W> {
+            (0..self.ids.len())
+                .filter_map(|vertex| self.eccentricity_at(vertex))
+                .fold(None, |least, eccentricity| match least {
+                    Some(least) if least <= eccentricity => Some(least),
+                    _ => Some(eccentricity),
+                })
+        }
+
+        /// Ids of the vertexes whose eccentricity equals the radius.
+        pub fn center(&self) -> Vec<I> {
+            let Some(radius) = self.radius() else {
+                return Vec::new();
+            };
+            (0..self.ids.len())
+                .filter(|&vertex| self.eccentricity_at(vertex) == Some(radius))
+                .map(|vertex| self.ids[vertex].clone())
+                .collect()
+        }
+    }
+
+    /// Searches for cheapest paths between vertexes. Edges without a value weigh zero.
+    pub trait ShortestPaths<T, V, I: VertexId = usize>: GraphView<T, V, I> {
+        /// Cheapest path from `start` to `goal` (A*), or `None` if `goal` can't be reached.
+        /// `heuristic` estimates the remaining cost from a vertex, given its id and value;
+        /// the path is the cheapest one as long as the estimate never exceeds the real cost.
+        /// Only the vertexes the search reaches are visited; a negative edge among them is an error.
+        fn astar<W, F, H>(
+            &self,
+            start: I,
+            goal: I,
+            weight: F,
+            heuristic: H,
+        ) -> Result<Option<Path<I, W>>, GraphError>
+        where
+            W: Weight,
+            F: Fn(&V) -> W,
+            H: Fn(&I, &T) -> W,
+        {
+            if self.get_vertex_by_id(goal.clone()).is_none() {
+                return Err(GraphError::VertexNotFound);
+            }
+            let estimate = |id: &I| {
+                self.get_vertex_by_id(id.clone())
+                    .map(|vertex| heuristic(id, vertex.value()))
+                    .ok_or(GraphError::VertexNotFound)
+            };
+
+            // Vertexes get a dense index once the search reaches them.
+            let mut ids = vec![start.clone()];
+            let mut index = HashMap::from([(start.clone(), 0)]);
+            let mut lengths = vec![W::default()];
+            let mut parents = vec![usize::MAX];
+            let mut heap = BinaryHeap::from([Reverse((
+                Ordered(estimate(&start)?),
+                Ordered(W::default()),
+                0,
+            ))]);
+            while let Some(Reverse((_, Ordered(length), vertex))) = heap.pop() {
+                // Skip entries superseded by a cheaper path to the same vertex.
+                if lengths[vertex] < length {
+                    continue;
+                }
+                if ids[vertex] == goal {
+                    let mut vertices = vec![goal];
+                    let mut vertex = vertex;
+                    while parents[vertex] != usize::MAX {
+                        vertex = parents[vertex];
+                        vertices.push(ids[vertex].clone());
+                    }
+                    vertices.reverse();
+                    return Ok(Some(Path {
+                        vertices,
+                        cost: length,
+                    }));
+                }
+                let edges: Vec<(I, W)> = self
+                    .out_edges(ids[vertex].clone())
+                    .filter_map(|edge| {
+                        let weight = edge.value().map(&weight).unwrap_or_default();
+                        Some((edge.end_id()?, weight))
+                    })
+                    .collect();
+                for (next, weight) in edges {
+                    if weight < W::default() {
+                        return Err(GraphError::NegativeWeightError);
+                    }
+                    let candidate = length + weight;
+                    let next = match index.get(&next) {
+                        Some(&next) if candidate >= lengths[next] => continue,
+                        Some(&next) => next,
+                        None => {
+                            index.insert(next.clone(), ids.len());
+                            ids.push(next);
+                            lengths.push(candidate);
+                            parents.push(usize::MAX);
+                            ids.len() - 1
+                        }
+                    };
+                    lengths[next] = candidate;
+                    parents[next] = vertex;
+                    heap.push(Reverse((
+                        Ordered(candidate + estimate(&ids[next])?),
+                        Ordered(candidate),
+                        next,
+                    )));
+                }
+            }
+            Ok(None)
+        }
+
+        /// Shortest paths between every pair of vertexes (Floyd–Warshall), best suited
+        /// to dense graphs. Weights may be negative, but not cycles.
+        fn floyd_warshall<W: Weight, F: Fn(&V) -> W>(
+            &self,
+            weight: F,
+        ) -> Result<AllPairs<I, W>, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let len = adjacency.len();
+            let mut lengths: Vec<Vec<Option<W>>> = vec![vec![None; len]; len];
+            let mut parents = vec![vec![usize::MAX; len]; len];
+            for (start, edges) in adjacency.weighted(self, weight).into_iter().enumerate() {
+                for (end, weight) in edges {
+                    if lengths[start][end].is_none_or(|known| weight < known) {
+                        lengths[start][end] = Some(weight);
+                        parents[start][end] = start;
+                    }
+                }
+            }
+            for (vertex, row) in lengths.iter_mut().enumerate() {
+                if row[vertex].is_some_and(|length| length < W::default()) {
+                    return Err(GraphError::NegativeCycleError);
+                }
+                row[vertex] = Some(W::default());
+            }
+
+            for middle in 0..len {
+                for start in 0..len {
+                    let Some(first) = lengths[start][middle] else {
+                        continue;
+                    };
+                    for end in 0..len {
+                        let Some(second) = lengths[middle][end] else {
+                            continue;
+                        };
+                        let candidate = first + second;
+                        if lengths[start][end].is_none_or(|known| candidate < known) {
+                            lengths[start][end] = Some(candidate);
+                            parents[start][end] = parents[middle][end];
+                        }
+                    }
+                    if lengths[start][start].is_some_and(|length| length < W::default()) {
+                        return Err(GraphError::NegativeCycleError);
+                    }
+                }
+            }
+            Ok(AllPairs::new(adjacency, lengths, parents))
+        }
+
+        /// Shortest paths between every pair of vertexes (Johnson), best suited to sparse
+        /// graphs. Weights may be negative, but not cycles.
+        fn johnson<W: Weight, F: Fn(&V) -> W>(
+            &self,
+            weight: F,
+        ) -> Result<AllPairs<I, W>, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let len = adjacency.len();
+            let mut weighted = adjacency.weighted(self, weight);
+            // Potentials are distances from an extra vertex joined to every other one.
+            weighted.push((0..len).map(|vertex| (vertex, W::default())).collect());
+            let potentials: Vec<W> = bellman_ford(&weighted, len)?
+                .into_iter()
+                .map(Option::unwrap_or_default)
+                .collect();
+            weighted.pop();
+            for (start, edges) in weighted.iter_mut().enumerate() {
+                for (end, weight) in edges {
+                    *weight = *weight + potentials[start] - potentials[*end];
+                }
+            }
+
+            let (mut lengths, mut parents) = (Vec::with_capacity(len), Vec::with_capacity(len));
+            for start in 0..len {
+                let (reweighted, tree) = dijkstra_tree(&weighted, start);
+                lengths.push(
+                    reweighted
+                        .into_iter()
+                        .enumerate()
+                        .map(|(end, length)| Some(length? - potentials[start] + potentials[end]))
+                        .collect(),
+                );
+                parents.push(tree);
+            }
+            Ok(AllPairs::new(adjacency, lengths, parents))
+        }
+
+        /// Up to `k` cheapest paths from `start` to `end` that visit no vertex twice (Yen),
+        /// cheapest first. Paths of equal cost come in the order of their vertex indexes.
+        fn k_shortest_paths<W: Weight, F: Fn(&V) -> W>(
+            &self,
+            start: I,
+            end: I,
+            k: usize,
+            weight: F,
+        ) -> Result<Vec<Path<I, W>>, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let start = adjacency.index_of(&start)?;
+            let end = adjacency.index_of(&end)?;
+            let weighted = adjacency.weighted(self, weight);
+            check_non_negative(&weighted)?;
+            // Only the cheapest of parallel edges can be on a shortest path.
+            let mut cheapest: Vec<HashMap<usize, W>> = vec![HashMap::new(); adjacency.len()];
+            for (vertex, edges) in weighted.iter().enumerate() {
+                for &(next, weight) in edges {
+                    let known = cheapest[vertex].entry(next).or_insert(weight);
+                    if weight < *known {
+                        *known = weight;
+                    }
+                }
+            }
+
+            let mut found: Vec<(W, Vec<usize>)> = Vec::new();
+            let mut candidates = BinaryHeap::new();
+            let mut seen = HashSet::new();
+            let no_vertexes = vec![false; adjacency.len()];
+            if let Some(first) = cheapest_path(&weighted, start, end, &no_vertexes, |_, _| true) {
+                seen.insert(first.1.clone());
+                candidates.push(Reverse((Ordered(first.0), first.1)));
+            }
+            while found.len() < k {
+                let Some(Reverse((Ordered(cost), path))) = candidates.pop() else {
+                    break;
+                };
+                // Deviate from the new path at every vertex but the last.
+                let mut root_cost = W::default();
+                let mut root_vertexes = vec![false; adjacency.len()];
+                for spur in 0..path.len() - 1 {
+                    let root = &path[..=spur];
+                    let removed: HashSet<usize> = found
+                        .iter()
+                        .map(|(_, known)| known)
+                        .chain([&path])
+                        .filter(|known| known.len() > spur + 1 && known.starts_with(root))
+                        .map(|known| known[spur + 1])
+                        .collect();
+                    let spur_path = cheapest_path(
+                        &weighted,
+                        path[spur],
+                        end,
+                        &root_vertexes,
+                        |vertex, next| vertex != path[spur] || !removed.contains(&next),
+                    );
+                    if let Some((spur_cost, spur_path)) = spur_path {
+                        let candidate: Vec<usize> =
+                            root.iter().chain(&spur_path[1..]).copied().collect();
+                        if seen.insert(candidate.clone()) {
+                            candidates.push(Reverse((Ordered(root_cost + spur_cost), candidate)));
+                        }
+                    }
+                    root_vertexes[path[spur]] = true;
+                    root_cost = root_cost + cheapest[path[spur]][&path[spur + 1]];
+                }
+                found.push((cost, path));
+            }
+
+            Ok(found
+                .into_iter()
+                .map(|(cost, path)| Path {
+                    vertices: path
+                        .into_iter()
+                        .map(|vertex| adjacency.ids[vertex].clone())
+                        .collect(),
+                    cost,
+                })
+                .collect())
+        }
+    }
+
+    impl<T, V, I: VertexId, G: GraphView<T, V, I>> ShortestPaths<T, V, I> for G {}
+
+    /// Cost and vertexes of the cheapest path from `start` to `end` (Dijkstra) that avoids
+    /// the `removed` vertexes and only takes the edges `allowed` accepts.
+    fn cheapest_path<W: Weight>(
+        weighted: &[Vec<(usize, W)>],
+        start: usize,
+        end: usize,
+        removed: &[bool],
+        allowed: impl Fn(usize, usize) -> bool,
+    ) -> Option<(W, Vec<usize>)> {
+        let mut lengths: Vec<Option<W>> = vec![None; weighted.len()];
+        let mut parents = vec![usize::MAX; weighted.len()];
+        let mut done = vec![false; weighted.len()];
+        let mut heap = BinaryHeap::from([Reverse((Ordered(W::default()), start))]);
+        lengths[start] = Some(W::default());
+        while let Some(Reverse((Ordered(length), vertex))) = heap.pop() {
+            if std::mem::replace(&mut done[vertex], true) {
+                continue;
+            }
+            if vertex == end {
+                let mut path = vec![end];
+                while *path.last()? != start {
+                    path.push(parents[*path.last()?]);
+                }
+                path.reverse();
+                return Some((length, path));
+            }
+            for &(next, weight) in &weighted[vertex] {
+                if removed[next] || !allowed(vertex, next) {
+                    continue;
+                }
+                let candidate = length + weight;
+                if lengths[next].is_none_or(|known| candidate < known) {
+                    lengths[next] = Some(candidate);
+                    parents[next] = vertex;
+                    heap.push(Reverse((Ordered(candidate), next)));
+                }
+            }
+        }
+        None
+    }
+}
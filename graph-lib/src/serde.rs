@@ -1,168 +1,270 @@
 pub mod serde_graph {
-    use std::cell::{Ref, RefCell};
     use std::fmt::Debug;
-    use std::rc::Rc;
     use std::str::FromStr;
 
-    use crate::edge::edge::{DefaultEdge, DefaultOrientedEdge, OrientedEdge};
-    use crate::error::{EdgeParseError, GraphParseError, SerializationError, VertexParseError};
-    use crate::graph::graph::{DefaultGraph, OrientedGraph};
-    use crate::vertex::vertex::{DefaultVertex, Vertex};
+    use crate::edge::edge::{DefaultOrientedEdge, OrientedEdge};
+    use crate::error::{
+        EdgeParseError, GraphError, GraphParseError, SerializationError, VertexParseError,
+    };
+    use crate::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
+    use crate::vertex::vertex::{DefaultVertex, Vertex, VertexId};
 
-    pub trait SerializeGraph<T, V> {
-        type VertexType: DefaultVertex<T, V>;
-        type EdgeType: DefaultEdge<T, V>;
-        type GraphType: DefaultGraph<T, V>;
+    pub trait SerializeGraph<T, V, I = usize> {
+        type VertexType: DefaultVertex<T, V, I>;
+        type EdgeType: DefaultOrientedEdge<T, V, I>;
+        type GraphType: GraphView<T, V, I>;
         fn serialize(&self) -> Result<String, SerializationError>;
-        fn serialize_vertex(vertex: Ref<Self::VertexType>) -> String;
-        fn serialize_edge(edge: Ref<Self::EdgeType>) -> Result<String, SerializationError>;
+        fn serialize_vertex(vertex: &Self::VertexType) -> String;
+        fn serialize_edge(edge: &Self::EdgeType) -> Result<String, SerializationError>;
     }
 
-    pub trait DeserializeGraph<T: Debug, V: Debug> {
-        type VertexType: DefaultVertex<T, V>;
-        type EdgeType: DefaultEdge<T, V>;
-        type GraphType: DefaultGraph<T, V>;
-        fn deserialize(graph: &str) -> Result<Self::GraphType, GraphParseError>;
+    pub trait DeserializeGraph<T: Debug, V: Debug, I = usize> {
+        type VertexType: DefaultVertex<T, V, I>;
+        type EdgeType: DefaultOrientedEdge<T, V, I>;
+        type GraphType: DefaultGraph<T, V, I>;
+        fn deserialize(graph: &str) -> Result<Self::GraphType, GraphParseError> {
+            Self::deserialize_with_policy(graph, GraphPolicy::default())
+        }
+        fn deserialize_with_policy(
+            graph: &str,
+            policy: GraphPolicy,
+        ) -> Result<Self::GraphType, GraphParseError>;
         fn deserialize_vertex(vertex: &str) -> Result<Self::VertexType, VertexParseError>;
         fn deserialize_edge(
             edge: &str,
-            vertexes: Vec<Rc<RefCell<Self::VertexType>>>,
+            graph: &Self::GraphType,
         ) -> Result<Self::EdgeType, EdgeParseError>;
     }
 
-    impl<T: FromStr + Debug, V: FromStr + Debug + Clone> DeserializeGraph<T, V>
-        for OrientedGraph<T, V>
+    pub(crate) fn parse_vertex<T: FromStr, I: FromStr>(
+        vertex: &str,
+    ) -> Result<(I, T), VertexParseError> {
+        if let Some((index, value)) = vertex.split_once(char::is_whitespace) {
+            let vertex_id = index
+                .parse::<I>()
+                .map_err(|_| VertexParseError::VertexIndexParsingError);
+            let value = value
+                .parse::<T>()
+                .map_err(|_| VertexParseError::VertexValueParsingError);
+            return Ok((vertex_id?, value?));
+        }
+        Err(VertexParseError::VertexParsingError)
+    }
+
+    /// Splits an edge line into its optional id, start id, end id and value.
+    ///
+    /// A leading token ending in `:` is the edge id, unless the rest of the line doesn't
+    /// join two vertexes `has_vertex` knows and the whole line does: then the token is the
+    /// id of a start vertex such as `a:` in a line written without edge ids.
+    pub(crate) fn parse_edge<V: FromStr, I: FromStr>(
+        edge: &str,
+        has_vertex: impl Fn(&I) -> bool,
+    ) -> Result<(Option<usize>, I, I, V), EdgeParseError> {
+        let with_id = match edge.split_once(char::is_whitespace) {
+            Some((id, rest)) if id.ends_with(':') => id
+                .trim_end_matches(':')
+                .parse::<usize>()
+                .map_err(|_| EdgeParseError::EdgeIdParsingError)
+                .and_then(|id| {
+                    let (start, end, value) = parse_edge_ends(rest)?;
+                    Ok((Some(id), start, end, value))
+                }),
+            _ => {
+                let (start, end, value) = parse_edge_ends(edge)?;
+                return Ok((None, start, end, value));
+            }
+        };
+        let joins = |start: &I, end: &I| has_vertex(start) && has_vertex(end);
+        if matches!(&with_id, Ok((_, start, end, _)) if joins(start, end)) {
+            return with_id;
+        }
+        match parse_edge_ends(edge) {
+            Ok((start, end, value)) if joins(&start, &end) => Ok((None, start, end, value)),
+            _ => with_id,
+        }
+    }
+
+    /// Splits the start id, end id and value off an edge line without an edge id.
+    fn parse_edge_ends<V: FromStr, I: FromStr>(edge: &str) -> Result<(I, I, V), EdgeParseError> {
+        let (start, end_with_value) = edge
+            .split_once(char::is_whitespace)
+            .ok_or(EdgeParseError::EdgeParsingError)?;
+        let start = start
+            .parse::<I>()
+            .map_err(|_| EdgeParseError::EdgeStartParsingError)?;
+
+        let (end, value) = end_with_value
+            .split_once(char::is_whitespace)
+            .ok_or(EdgeParseError::EdgeParsingError)?;
+
+        let end = end
+            .parse::<I>()
+            .map_err(|_| EdgeParseError::EdgeEndParsingError)?;
+        let value = value
+            .parse::<V>()
+            .map_err(|_| EdgeParseError::EdgeValueParsingError)?;
+        Ok((start, end, value))
+    }
+
+    pub(crate) fn deserialize_into<T, V, I, G>(
+        graph: &str,
+        mut graph_obj: G,
+    ) -> Result<G, GraphParseError>
+    where
+        T: Debug,
+        V: Debug,
+        G: DeserializeGraph<T, V, I, GraphType = G>
+            + DefaultGraph<
+                T,
+                V,
+                I,
+                VertexType = <G as DeserializeGraph<T, V, I>>::VertexType,
+                EdgeType = <G as DeserializeGraph<T, V, I>>::EdgeType,
+            >,
     {
-        type VertexType = Vertex<T, V>;
-        type EdgeType = OrientedEdge<T, V>;
-        type GraphType = OrientedGraph<T, V>;
+        let mut deser_edges = false;
+        for line in graph.lines() {
+            if line.starts_with('#') {
+                deser_edges = true;
+                continue;
+            }
+            if deser_edges {
+                graph_obj
+                    .add_edge(
+                        G::deserialize_edge(line, &graph_obj)
+                            .map_err(|_| GraphParseError::EdgeParsingError)?,
+                    )
+                    .map_err(|e| match e {
+                        GraphError::SelfLoopError | GraphError::EdgeExistsError => {
+                            GraphParseError::EdgePolicyError(e)
+                        }
+                        _ => GraphParseError::EdgeParsingError,
+                    })?;
+            } else {
+                graph_obj
+                    .add_vertex(
+                        G::deserialize_vertex(line)
+                            .map_err(|_| GraphParseError::VertexParsingError)?,
+                    )
+                    .map_err(|_| GraphParseError::VertexParsingError)?;
+            }
+        }
+        Ok(graph_obj)
+    }
 
-        fn deserialize(graph: &str) -> Result<Self::GraphType, GraphParseError> {
-            let mut graph_obj = Self::default();
-            let mut deser_edges = false;
-            for line in graph.lines() {
-                if line.starts_with("#") {
-                    deser_edges = true;
-                    continue;
+    pub(crate) fn serialize_from<T, V, I, G>(graph: &G) -> Result<String, SerializationError>
+    where
+        G: SerializeGraph<T, V, I>
+            + GraphView<
+                T,
+                V,
+                I,
+                VertexType = <G as SerializeGraph<T, V, I>>::VertexType,
+                EdgeType = <G as SerializeGraph<T, V, I>>::EdgeType,
+            >,
+    {
+        let mut result = String::new();
+        for vertex in graph.vertices() {
+            result.push_str(format!("{}\n", G::serialize_vertex(&vertex)).as_str());
+        }
+        result.push('#');
+        for edge in graph.edges() {
+            match G::serialize_edge(&edge) {
+                Ok(edge) => {
+                    result.push_str(format!("\n{}", edge).as_str());
                 }
-                if deser_edges {
-                    graph_obj
-                        .add_edge(
-                            Self::deserialize_edge(line, graph_obj.get_vertexes())
-                                .map_err(|_| GraphParseError::EdgeParsingError)?,
-                        )
-                        .map_err(|_| GraphParseError::EdgeParsingError)?;
-                } else {
-                    graph_obj
-                        .add_vertex(
-                            Self::deserialize_vertex(line)
-                                .map_err(|_| GraphParseError::VertexParsingError)?,
-                        )
-                        .map_err(|_| GraphParseError::VertexParsingError)?;
+                Err(e) => {
+                    return Err(e);
                 }
             }
-            Ok(graph_obj)
+        }
+        Ok(result)
+    }
+
+    pub(crate) fn vertex_line<T: ToString, V, I: ToString>(
+        vertex: &impl DefaultVertex<T, V, I>,
+    ) -> String {
+        format!(
+            "{} {}",
+            vertex.id().to_string(),
+            vertex.value().to_string().as_str()
+        )
+    }
+
+    pub(crate) fn edge_line<T, V: ToString, I: ToString>(
+        edge: &impl DefaultOrientedEdge<T, V, I>,
+    ) -> Result<String, SerializationError> {
+        if let (Some(id), Some(start), Some(end)) = (edge.id(), edge.start_id(), edge.end_id()) {
+            return Ok(format!(
+                "{}: {} {} {}",
+                id,
+                start.to_string(),
+                end.to_string(),
+                if let Some(val) = edge.value() {
+                    val.to_string()
+                } else {
+                    "".to_string()
+                }
+            ));
+        }
+        Err(SerializationError::EdgeVertexNotFound)
+    }
+
+    impl<T: FromStr + Debug, V: FromStr + Debug + Clone, I: VertexId> DeserializeGraph<T, V, I>
+        for OrientedGraph<T, V, I>
+    {
+        type VertexType = Vertex<T, V, I>;
+        type EdgeType = OrientedEdge<T, V, I>;
+        type GraphType = OrientedGraph<T, V, I>;
+
+        fn deserialize_with_policy(
+            graph: &str,
+            policy: GraphPolicy,
+        ) -> Result<Self::GraphType, GraphParseError> {
+            deserialize_into(graph, Self::with_policy(policy))
         }
 
         fn deserialize_vertex(vertex: &str) -> Result<Self::VertexType, VertexParseError> {
-            if let Some((index, value)) = vertex.split_once(char::is_whitespace) {
-                let vertex_id = index
-                    .parse::<usize>()
-                    .map_err(|_| VertexParseError::VertexIndexParsingError);
-                let value = value
-                    .parse::<T>()
-                    .map_err(|_| VertexParseError::VertexValueParsingError);
-                return Ok(Vertex::<T, V>::new(vertex_id?, value?));
-            }
-            Err(VertexParseError::VertexParsingError)
+            let (id, value) = parse_vertex(vertex)?;
+            Ok(Vertex::<T, V, I>::new(id, value))
         }
 
         fn deserialize_edge(
             edge: &str,
-            vertexes: Vec<Rc<RefCell<Self::VertexType>>>,
+            graph: &Self::GraphType,
         ) -> Result<Self::EdgeType, EdgeParseError> {
-            return if let Some((start, end_with_value)) = edge.split_once(char::is_whitespace) {
-                let start_vertex = start
-                    .parse::<usize>()
-                    .map_err(|_| EdgeParseError::EdgeEndParsingError)
-                    .and_then(|index| {
-                        vertexes
-                            .iter()
-                            .find(|&p| p.borrow().id() == index)
-                            .ok_or(EdgeParseError::VertexForEdgeIndexNotFound)
-                    })?;
-
-                let (end, value) = end_with_value
-                    .split_once(char::is_whitespace)
-                    .ok_or(EdgeParseError::EdgeParsingError)?;
-
-                let end_vertex = end
-                    .parse::<usize>()
-                    .map_err(|_| EdgeParseError::EdgeStartParsingError)
-                    .and_then(|index| {
-                        vertexes
-                            .iter()
-                            .find(|&p| p.borrow().id() == index)
-                            .ok_or(EdgeParseError::VertexForEdgeIndexNotFound)
-                    })?;
-                let value = value
-                    .parse::<V>()
-                    .map_err(|_| EdgeParseError::EdgeStartParsingError)?;
-                Ok(OrientedEdge::<T, V>::new(start_vertex, end_vertex, value))
-            } else {
-                Err(EdgeParseError::EdgeParsingError)
-            };
+            let (id, start, end, value) = parse_edge(edge, |id| graph.vertex_rc(id).is_some())?;
+            let start_vertex = graph
+                .vertex_rc(&start)
+                .ok_or(EdgeParseError::VertexForEdgeIndexNotFound)?;
+            let end_vertex = graph
+                .vertex_rc(&end)
+                .ok_or(EdgeParseError::VertexForEdgeIndexNotFound)?;
+            let mut edge = OrientedEdge::<T, V, I>::new(start_vertex, end_vertex, value);
+            if let Some(id) = id {
+                edge.set_id(id);
+            }
+            Ok(edge)
         }
     }
 
-    impl<T: Debug + ToString, V: Debug + ToString + Clone> SerializeGraph<T, V>
-        for OrientedGraph<T, V>
+    impl<T: Debug + ToString, V: Debug + ToString + Clone, I: VertexId> SerializeGraph<T, V, I>
+        for OrientedGraph<T, V, I>
     {
-        type VertexType = Vertex<T, V>;
-        type EdgeType = OrientedEdge<T, V>;
-        type GraphType = OrientedGraph<T, V>;
+        type VertexType = Vertex<T, V, I>;
+        type EdgeType = OrientedEdge<T, V, I>;
+        type GraphType = OrientedGraph<T, V, I>;
 
         fn serialize(&self) -> Result<String, SerializationError> {
-            let mut result = String::new();
-            for vertex in self.get_vertexes() {
-                result.push_str(format!("{}\n", Self::serialize_vertex(vertex.borrow())).as_str());
-            }
-            result.push('#');
-            for edge in self.get_edges() {
-                match Self::serialize_edge(edge.borrow()) {
-                    Ok(edge) => {
-                        result.push_str(format!("\n{}", edge).as_str());
-                    }
-                    Err(e) => {
-                        return Err(e);
-                    }
-                }
-            }
-            Ok(result)
+            serialize_from(self)
         }
 
-        fn serialize_vertex(vertex: Ref<Self::VertexType>) -> String {
-            String::from(format!(
-                "{} {}",
-                vertex.id().to_string(),
-                vertex.value().to_string().as_str()
-            ))
+        fn serialize_vertex(vertex: &Self::VertexType) -> String {
+            vertex_line(vertex)
         }
 
-        fn serialize_edge(edge: Ref<Self::EdgeType>) -> Result<String, SerializationError> {
-            if let (Some(start), Some(end)) = (edge.start(), edge.end()) {
-                return Ok(String::from(format!(
-                    "{} {} {}",
-                    start.borrow().id(),
-                    end.borrow().id(),
-                    if let Some(val) = edge.value() {
-                        val.to_string()
-                    } else {
-                        "".to_string()
-                    }
-                )));
-            }
-            Err(SerializationError::EdgeVertexNotFound)
+        fn serialize_edge(edge: &Self::EdgeType) -> Result<String, SerializationError> {
+            edge_line(edge)
         }
     }
 }
@@ -0,0 +1,321 @@
+pub mod shared {
+    use std::fmt::Debug;
+    use std::ops::Deref;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex, PoisonError, RwLock, RwLockWriteGuard};
+
+    use crate::arena::arena::{ArenaEdge, ArenaGraph, ArenaVertex};
+    use crate::error::{
+        EdgeParseError, GraphError, GraphParseError, SerializationError, VertexParseError,
+    };
+    use crate::graph::graph::{DefaultGraph, GraphPolicy, GraphView};
+    use crate::serde::serde_graph::{DeserializeGraph, SerializeGraph};
+
+    /// Vertex of a [`SharedGraph`] snapshot. Keeps the snapshot alive while it is held.
+    pub struct SharedVertex<T, V> {
+        graph: Arc<ArenaGraph<T, V>>,
+        slot: usize,
+    }
+
+    impl<T, V> Deref for SharedVertex<T, V> {
+        type Target = ArenaVertex<T, V>;
+
+        fn deref(&self) -> &Self::Target {
+            self.graph.vertices[self.slot]
+                .as_ref()
+                .expect("snapshots are never modified")
+        }
+    }
+
+    /// Edge of a [`SharedGraph`] snapshot. Keeps the snapshot alive while it is held.
+    pub struct SharedEdge<T, V> {
+        graph: Arc<ArenaGraph<T, V>>,
+        slot: usize,
+    }
+
+    impl<T, V> Deref for SharedEdge<T, V> {
+        type Target = ArenaEdge<T, V>;
+
+        fn deref(&self) -> &Self::Target {
+            self.graph.edge_at(self.slot)
+        }
+    }
+
+    /// `Send + Sync` handle to an [`ArenaGraph`] shared between threads.
+    ///
+    /// Every clone of the handle refers to the same graph. Reads run against an immutable
+    /// snapshot and never wait for each other; a write copies the graph only if some snapshot
+    /// taken before it is still alive, so readers keep seeing a consistent graph.
+    /// Writes wait for each other, but never block readers while copying.
+    #[derive(Debug)]
+    pub struct SharedGraph<T, V> {
+        graph: Arc<RwLock<Arc<ArenaGraph<T, V>>>>,
+        writer: Arc<Mutex<()>>,
+    }
+
+    impl<T, V> Clone for SharedGraph<T, V> {
+        fn clone(&self) -> Self {
+            Self {
+                graph: self.graph.clone(),
+                writer: self.writer.clone(),
+            }
+        }
+    }
+
+    impl<T, V> Default for SharedGraph<T, V> {
+        fn default() -> Self {
+            Self::new(ArenaGraph::default())
+        }
+    }
+
+    impl<T, V> From<ArenaGraph<T, V>> for SharedGraph<T, V> {
+        fn from(graph: ArenaGraph<T, V>) -> Self {
+            Self::new(graph)
+        }
+    }
+
+    impl<T, V> SharedGraph<T, V> {
+        pub fn new(graph: ArenaGraph<T, V>) -> Self {
+            Self {
+                graph: Arc::new(RwLock::new(Arc::new(graph))),
+                writer: Arc::new(Mutex::new(())),
+            }
+        }
+
+        pub fn with_policy(policy: GraphPolicy) -> Self {
+            Self::new(ArenaGraph::with_policy(policy))
+        }
+
+        /// Current state of the graph. Later writes through any handle don't affect it.
+        pub fn snapshot(&self) -> Arc<ArenaGraph<T, V>> {
+            self.graph
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        }
+
+        fn current(&self) -> RwLockWriteGuard<'_, Arc<ArenaGraph<T, V>>> {
+            self.graph.write().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+
+    impl<T: Clone, V: Clone> SharedGraph<T, V> {
+        /// Applies `f` to the graph.
+        ///
+        /// While snapshots are alive `f` runs on a copy that replaces the graph once `f`
+        /// returns, so a panic in `f` leaves the graph unchanged. Otherwise `f` changes the
+        /// graph in place and a panic keeps whatever `f` did before it.
+        ///
+        /// The in-place path runs `f` while holding the lock readers take, so reads through
+        /// any handle wait until `f` returns. `f` must not use this graph through any handle:
+        /// a read deadlocks on the in-place path, and a nested `update` deadlocks on both.
+        pub fn update<R>(&self, f: impl FnOnce(&mut ArenaGraph<T, V>) -> R) -> R {
+            let _writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Some(graph) = Arc::get_mut(&mut self.current()) {
+                return f(graph);
+            }
+
+            let mut graph = ArenaGraph::clone(&self.snapshot());
+            let result = f(&mut graph);
+            *self.current() = Arc::new(graph);
+            result
+        }
+    }
+
+    impl<T: Clone, V: Clone> GraphView<T, V> for SharedGraph<T, V> {
+        type VertexType = ArenaVertex<T, V>;
+        type EdgeType = ArenaEdge<T, V>;
+        type VertexRef<'a>
+            = SharedVertex<T, V>
+        where
+            Self: 'a;
+        type EdgeRef<'a>
+            = SharedEdge<T, V>
+        where
+            Self: 'a;
+
+        fn vertex_count(&self) -> usize {
+            self.snapshot().vertex_count()
+        }
+
+        fn edges_count(&self) -> usize {
+            self.snapshot().edges_count()
+        }
+
+        fn vertices(&self) -> impl Iterator<Item = Self::VertexRef<'_>> {
+            let graph = self.snapshot();
+            (0..graph.vertices.len()).filter_map(move |slot| {
+                graph.vertices[slot].as_ref().map(|_| SharedVertex {
+                    graph: graph.clone(),
+                    slot,
+                })
+            })
+        }
+
+        fn edges(&self) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            let graph = self.snapshot();
+            let mut from = 0;
+            std::iter::from_fn(move || {
+                let (&id, &slot) = graph.edge_slots.range(from..).next()?;
+                from = id + 1;
+                Some(SharedEdge {
+                    graph: graph.clone(),
+                    slot,
+                })
+            })
+        }
+
+        fn edge_ids(&self) -> impl Iterator<Item = usize> {
+            let graph = self.snapshot();
+            let mut from = 0;
+            std::iter::from_fn(move || {
+                let (&id, _) = graph.edge_slots.range(from..).next()?;
+                from = id + 1;
+                Some(id)
+            })
+        }
+
+        fn neighbors(&self, id: usize) -> impl Iterator<Item = usize> {
+            self.out_edges(id).map(|edge| edge.end)
+        }
+
+        fn out_edges(&self, id: usize) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            let graph = self.snapshot();
+            let vertex = graph.vertex_slots.get(&id).copied();
+            let count = vertex
+                .and_then(|slot| graph.vertices[slot].as_ref())
+                .map_or(0, |vertex| vertex.edges.len());
+            (0..count).filter_map(move |position| {
+                let (slot, _) = graph.vertices[vertex?].as_ref()?.edges[position];
+                (graph.edge_at(slot).start == id).then(|| SharedEdge {
+                    graph: graph.clone(),
+                    slot,
+                })
+            })
+        }
+
+        fn get_vertex_by_id(&self, id: usize) -> Option<Self::VertexRef<'_>> {
+            let graph = self.snapshot();
+            let slot = *graph.vertex_slots.get(&id)?;
+            Some(SharedVertex { graph, slot })
+        }
+
+        fn get_edge_by_id(&self, id: usize) -> Option<Self::EdgeRef<'_>> {
+            let graph = self.snapshot();
+            let slot = *graph.edge_slots.get(&id)?;
+            Some(SharedEdge { graph, slot })
+        }
+
+        fn get_edge_by_vertexes_id(&self, start: usize, end: usize) -> Option<Self::EdgeRef<'_>> {
+            self.get_edges_by_vertexes_id(start, end).next()
+        }
+
+        fn get_edges_by_vertexes_id(
+            &self,
+            start: usize,
+            end: usize,
+        ) -> impl Iterator<Item = Self::EdgeRef<'_>> {
+            self.out_edges(start).filter(move |edge| edge.end == end)
+        }
+    }
+
+    impl<T: Clone, V: Clone> DefaultGraph<T, V> for SharedGraph<T, V> {
+        fn add_edge(&mut self, edge: Self::EdgeType) -> Result<usize, GraphError> {
+            self.update(|graph| graph.add_edge(edge))
+        }
+
+        fn add_edge_with_vertex_id(
+            &mut self,
+            start: usize,
+            end: usize,
+            value: Option<V>,
+        ) -> Result<usize, GraphError> {
+            self.update(|graph| graph.add_edge_with_vertex_id(start, end, value))
+        }
+
+        fn remove_edge_by_id(&mut self, id: usize) -> Result<(), GraphError> {
+            self.update(|graph| graph.remove_edge_by_id(id))
+        }
+
+        fn remove_edge_by_vertexes(
+            &mut self,
+            start_vertex_id: usize,
+            end_vertex_id: usize,
+        ) -> Result<(), GraphError> {
+            self.update(|graph| graph.remove_edge_by_vertexes(start_vertex_id, end_vertex_id))
+        }
+
+        fn add_vertex(&mut self, vertex: Self::VertexType) -> Result<(), GraphError> {
+            self.update(|graph| graph.add_vertex(vertex))
+        }
+
+        fn add_raw_vertex(&mut self, id: usize, value: T) -> Result<(), GraphError> {
+            self.update(|graph| graph.add_raw_vertex(id, value))
+        }
+
+        fn insert_vertex(&mut self, value: T) -> Result<usize, GraphError> {
+            self.update(|graph| graph.insert_vertex(value))
+        }
+
+        fn remove_vertex_by_id(&mut self, id: usize) -> Result<(), GraphError> {
+            self.update(|graph| graph.remove_vertex_by_id(id))
+        }
+
+        fn update_vertex<F: FnOnce(&mut T)>(&mut self, id: usize, f: F) -> Result<(), GraphError> {
+            self.update(|graph| graph.update_vertex(id, f))
+        }
+
+        fn update_edge<F: FnOnce(Option<&mut V>)>(
+            &mut self,
+            start: usize,
+            end: usize,
+            f: F,
+        ) -> Result<(), GraphError> {
+            self.update(|graph| graph.update_edge(start, end, f))
+        }
+    }
+
+    impl<T: FromStr + Debug + Clone, V: FromStr + Debug + Clone> DeserializeGraph<T, V>
+        for SharedGraph<T, V>
+    {
+        type VertexType = ArenaVertex<T, V>;
+        type EdgeType = ArenaEdge<T, V>;
+        type GraphType = SharedGraph<T, V>;
+
+        fn deserialize_with_policy(
+            graph: &str,
+            policy: GraphPolicy,
+        ) -> Result<Self::GraphType, GraphParseError> {
+            ArenaGraph::deserialize_with_policy(graph, policy).map(Self::new)
+        }
+
+        fn deserialize_vertex(vertex: &str) -> Result<Self::VertexType, VertexParseError> {
+            ArenaGraph::<T, V>::deserialize_vertex(vertex)
+        }
+
+        fn deserialize_edge(
+            edge: &str,
+            graph: &Self::GraphType,
+        ) -> Result<Self::EdgeType, EdgeParseError> {
+            ArenaGraph::deserialize_edge(edge, &graph.snapshot())
+        }
+    }
+
+    impl<T: ToString + Clone, V: ToString + Clone> SerializeGraph<T, V> for SharedGraph<T, V> {
+        type VertexType = ArenaVertex<T, V>;
+        type EdgeType = ArenaEdge<T, V>;
+        type GraphType = SharedGraph<T, V>;
+
+        fn serialize(&self) -> Result<String, SerializationError> {
+            self.snapshot().serialize()
+        }
+
+        fn serialize_vertex(vertex: &Self::VertexType) -> String {
+            ArenaGraph::serialize_vertex(vertex)
+        }
+
+        fn serialize_edge(edge: &Self::EdgeType) -> Result<String, SerializationError> {
+            ArenaGraph::serialize_edge(edge)
+        }
+    }
+}
@@ -0,0 +1,134 @@
+pub mod spanning {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::graph::graph::GraphView;
+    use crate::union_find::union_find::UnionFind;
+    use crate::vertex::vertex::VertexId;
+    use crate::weight::weight::{Ordered, Weight};
+
+    /// Edges of a minimum spanning forest and their total weight.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SpanningForest<W> {
+        /// Ids of the chosen edges.
+        pub edges: Vec<usize>,
+        pub total_weight: W,
+    }
+
+    /// Minimum spanning forests, ignoring edge direction. Every connected component
+    /// of the graph gets a tree of its own; self-loops are never chosen.
+    pub trait SpanningTree<T, V, I: VertexId = usize>: GraphView<T, V, I> {
+        /// Kruskal's algorithm. Edges without a value weigh zero.
+        fn kruskal<W: Weight, F: Fn(&V) -> W>(&self, weight: F) -> SpanningForest<W> {
+            let adjacency = Adjacency::new(self);
+            let mut edges: Vec<(usize, usize, usize, W)> = weighted_edges(&adjacency, self, weight)
+                .into_iter()
+                .enumerate()
+                .flat_map(|(start, edges)| {
+                    edges
+                        .into_iter()
+                        .map(move |(end, edge, weight)| (start, end, edge, weight))
+                })
+                .collect();
+            edges.sort_by_key(|&(_, _, _, weight)| Ordered(weight));
+
+            let mut sets = UnionFind::new(adjacency.len());
+            let mut forest = SpanningForest {
+                edges: Vec::new(),
+                total_weight: W::default(),
+            };
+            for (start, end, edge, weight) in edges {
+                if sets.set_count() == 1 {
+                    break;
+                }
+                if sets.union(start, end) {
+                    forest.edges.push(edge);
+                    forest.total_weight = forest.total_weight + weight;
+                }
+            }
+            forest
+        }
+
+        /// Prim's algorithm, growing a tree from the first unvisited vertex of every
+        /// component. Edges without a value weigh zero.
+        fn prim<W: Weight, F: Fn(&V) -> W>(&self, weight: F) -> SpanningForest<W> {
+            let adjacency = Adjacency::new(self);
+            let mut undirected: Vec<Vec<(usize, usize, W)>> = vec![Vec::new(); adjacency.len()];
+            for (start, edges) in weighted_edges(&adjacency, self, weight)
+                .into_iter()
+                .enumerate()
+            {
+                for (end, edge, weight) in edges {
+                    undirected[start].push((end, edge, weight));
+                    undirected[end].push((start, edge, weight));
+                }
+            }
+
+            let mut in_tree = vec![false; adjacency.len()];
+            let mut forest = SpanningForest {
+                edges: Vec::new(),
+                total_weight: W::default(),
+            };
+            // Candidates are ordered by weight, then by the order they were found in.
+            let mut heap = BinaryHeap::new();
+            let mut found = 0usize;
+            for root in 0..adjacency.len() {
+                if in_tree[root] {
+                    continue;
+                }
+                in_tree[root] = true;
+                let mut vertex = root;
+                loop {
+                    for &(next, edge, weight) in &undirected[vertex] {
+                        if !in_tree[next] {
+                            heap.push(Reverse((Ordered(weight), found, next, edge)));
+                            found += 1;
+                        }
+                    }
+                    let Some(Reverse((Ordered(weight), _, next, edge))) =
+                        std::iter::from_fn(|| heap.pop())
+                            .find(|Reverse((_, _, next, _))| !in_tree[*next])
+                    else {
+                        break;
+                    };
+                    in_tree[next] = true;
+                    forest.edges.push(edge);
+                    forest.total_weight = forest.total_weight + weight;
+                    vertex = next;
+                }
+            }
+            forest
+        }
+    }
+
+    impl<T, V, I: VertexId, G: GraphView<T, V, I>> SpanningTree<T, V, I> for G {}
+
+    /// End index, id and weight of the edges starting in every vertex, self-loops left out.
+    fn weighted_edges<T, V, I, G, W, F>(
+        adjacency: &Adjacency<I>,
+        graph: &G,
+        weight: F,
+    ) -> Vec<Vec<(usize, usize, W)>>
+    where
+        I: VertexId,
+        G: GraphView<T, V, I> + ?Sized,
+        W: Weight,
+        F: Fn(&V) -> W,
+    {
+        adjacency
+            .weighted(graph, weight)
+            .into_iter()
+            .zip(&adjacency.out)
+            .enumerate()
+            .map(|(start, (weighted, out))| {
+                weighted
+                    .into_iter()
+                    .zip(out)
+                    .filter(|&((end, _), _)| end != start)
+                    .map(|((end, weight), &(_, edge))| (end, edge, weight))
+                    .collect()
+            })
+            .collect()
+    }
+}
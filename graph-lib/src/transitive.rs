@@ -0,0 +1,131 @@
+pub mod transitive {
+    use std::collections::HashSet;
+    use std::fmt::Debug;
+
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::edge::edge::{DefaultEdge, DefaultOrientedEdge};
+    use crate::error::GraphError;
+    use crate::graph::graph::{DefaultGraph, GraphView, OrientedGraph};
+    use crate::vertex::vertex::{DefaultVertex, VertexId};
+
+    impl<T: Debug + Clone, V: Debug + Clone, I: VertexId> OrientedGraph<T, V, I> {
+        /// Graph with the same vertexes and an edge from every vertex to each vertex
+        /// reachable from it, itself included if it lies on a cycle. Existing edges keep
+        /// their ids and values; `edge_value` gives the value of every added edge. Self-loops
+        /// are left out if the policy forbids them.
+        pub fn transitive_closure<F: Fn(&I, &I) -> Option<V>>(&self, edge_value: F) -> Self {
+            let adjacency = Adjacency::new(self);
+            let successors = adjacency.successors();
+            let mut closure = self.copy_with_edges(|_| true);
+            // Both arrays hold the last start vertex that marked the vertex.
+            let mut reached = vec![usize::MAX; adjacency.len()];
+            let mut direct = vec![usize::MAX; adjacency.len()];
+            for start in 0..adjacency.len() {
+                let mut stack = successors[start].clone();
+                for &next in &stack {
+                    reached[next] = start;
+                    direct[next] = start;
+                }
+                while let Some(vertex) = stack.pop() {
+                    for &next in &successors[vertex] {
+                        if reached[next] != start {
+                            reached[next] = start;
+                            stack.push(next);
+                        }
+                    }
+                    if direct[vertex] == start || (vertex == start && !self.policy().self_loops) {
+                        continue;
+                    }
+                    let (start, end) = (&adjacency.ids[start], &adjacency.ids[vertex]);
+                    // The pair has no edge yet, so the policy can't reject it.
+                    let _ = closure.add_edge_with_vertex_id(
+                        start.clone(),
+                        end.clone(),
+                        edge_value(start, end),
+                    );
+                }
+            }
+            closure
+        }
+
+        /// Graph with the same vertexes and the fewest edges that keep every vertex
+        /// reachable from the same ones. The kept edges keep their ids and values; of
+        /// parallel edges only the first one is kept. Fails on graphs with cycles.
+        /// Searches once from every vertex, so it takes O(V·E) time and O(V) extra memory.
+        pub fn transitive_reduction(&self) -> Result<Self, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let successors = adjacency.successors();
+            if topological_order(&successors).is_none() {
+                return Err(GraphError::CycleError);
+            }
+
+            // An edge is redundant if its end can be reached through another successor.
+            // Both arrays hold the last start vertex that marked the vertex.
+            let mut reached = vec![usize::MAX; adjacency.len()];
+            let mut kept_from = vec![usize::MAX; adjacency.len()];
+            let mut kept = HashSet::new();
+            for start in 0..adjacency.len() {
+                let mut stack = successors[start].clone();
+                while let Some(vertex) = stack.pop() {
+                    for &next in &successors[vertex] {
+                        if reached[next] != start {
+                            reached[next] = start;
+                            stack.push(next);
+                        }
+                    }
+                }
+                for &(next, edge) in &adjacency.out[start] {
+                    if reached[next] != start && kept_from[next] != start {
+                        kept_from[next] = start;
+                        kept.insert(edge);
+                    }
+                }
+            }
+            Ok(self.copy_with_edges(|edge| kept.contains(&edge)))
+        }
+
+        /// Copy of the vertexes and of the edges whose ids `keep` accepts, with the same
+        /// id counters, so ids freed in this graph aren't handed out again.
+        fn copy_with_edges(&self, keep: impl Fn(usize) -> bool) -> Self {
+            let mut copy = Self::with_policy(self.policy());
+            for vertex in self.vertices() {
+                // Ids are unique in this graph, so insertion can't fail.
+                let _ = copy.add_raw_vertex(vertex.id(), vertex.value().clone());
+            }
+            for edge in self.edges() {
+                if let (Some(id), Some(start), Some(end)) =
+                    (edge.id(), edge.start_id(), edge.end_id())
+                {
+                    if keep(id) {
+                        // This graph already satisfied the policy.
+                        let _ = copy.insert_edge(id, start, end, edge.value().cloned());
+                    }
+                }
+            }
+            copy.restore_ids(self.next_edge_id(), self.vertex_ids().clone());
+            copy
+        }
+    }
+
+    /// Vertexes in an order where every edge leads forward, or `None` if there is a cycle.
+    fn topological_order(successors: &[Vec<usize>]) -> Option<Vec<usize>> {
+        let mut incoming = vec![0; successors.len()];
+        for &next in successors.iter().flatten() {
+            incoming[next] += 1;
+        }
+        let mut order: Vec<usize> = (0..successors.len())
+            .filter(|&vertex| incoming[vertex] == 0)
+            .collect();
+        let mut position = 0;
+        while let Some(&vertex) = order.get(position) {
+            position += 1;
+            for &next in &successors[vertex] {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    order.push(next);
+                }
+            }
+        }
+        (order.len() == successors.len()).then_some(order)
+    }
+}
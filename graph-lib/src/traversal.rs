@@ -0,0 +1,188 @@
+pub mod traversal {
+    use std::collections::{HashMap, HashSet, VecDeque};
+
+    use crate::adjacency::adjacency::Adjacency;
+    use crate::error::GraphError;
+    use crate::graph::graph::GraphView;
+    use crate::vertex::vertex::VertexId;
+
+    /// Traversals available on every graph representation, mutable or frozen.
+    pub trait Traversal<T, V, I: VertexId = usize>: GraphView<T, V, I> {
+        /// Ids of the vertexes reachable from `start` in depth-first preorder.
+        fn depth_first_order(&self, start: I) -> Result<Vec<I>, GraphError> {
+            if self.get_vertex_by_id(start.clone()).is_none() {
+                return Err(GraphError::VertexNotFound);
+            }
+            let mut visited = HashSet::from([start.clone()]);
+            let mut order = vec![start.clone()];
+            let mut stack = vec![self.neighbors(start)];
+            while let Some(neighbors) = stack.last_mut() {
+                match neighbors.next() {
+                    Some(next) if visited.insert(next.clone()) => {
+                        order.push(next.clone());
+                        stack.push(self.neighbors(next));
+                    }
+                    Some(_) => {}
+                    None => {
+                        stack.pop();
+                    }
+                }
+            }
+            Ok(order)
+        }
+
+        /// Ids of the vertexes reachable from `start` in breadth-first order.
+        fn breadth_first_order(&self, start: I) -> Result<Vec<I>, GraphError> {
+            if self.get_vertex_by_id(start.clone()).is_none() {
+                return Err(GraphError::VertexNotFound);
+            }
+            let mut visited = HashSet::from([start.clone()]);
+            let mut order = Vec::new();
+            let mut queue = VecDeque::from([start]);
+            while let Some(id) = queue.pop_front() {
+                order.push(id.clone());
+                for next in self.neighbors(id) {
+                    if visited.insert(next.clone()) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+            Ok(order)
+        }
+
+        /// Component number of every vertex, ignoring edge direction. Components are
+        /// numbered from zero in the order their first vertex appears in `vertices()`.
+        fn weakly_connected_components(&self) -> HashMap<I, usize> {
+            let adjacency = Adjacency::new(self);
+            let mut components = vec![usize::MAX; adjacency.len()];
+            let mut count = 0;
+            for root in 0..adjacency.len() {
+                if components[root] != usize::MAX {
+                    continue;
+                }
+                components[root] = count;
+                let mut queue = VecDeque::from([root]);
+                while let Some(vertex) = queue.pop_front() {
+                    for next in adjacency.undirected(vertex) {
+                        if components[next] == usize::MAX {
+                            components[next] = count;
+                            queue.push_back(next);
+                        }
+                    }
+                }
+                count += 1;
+            }
+            adjacency.by_id(components)
+        }
+
+        /// Ids of the vertexes reachable from `start`, `start` included.
+        fn reachable_from(&self, start: I) -> Result<HashSet<I>, GraphError> {
+            Ok(self.breadth_first_order(start)?.into_iter().collect())
+        }
+
+        /// Whether a path of outgoing edges leads from `start` to `end`.
+        /// Every vertex is reachable from itself.
+        fn is_reachable(&self, start: I, end: I) -> Result<bool, GraphError> {
+            if self.get_vertex_by_id(start.clone()).is_none()
+                || self.get_vertex_by_id(end.clone()).is_none()
+            {
+                return Err(GraphError::VertexNotFound);
+            }
+            let mut visited = HashSet::from([start.clone()]);
+            let mut queue = VecDeque::from([start]);
+            while let Some(id) = queue.pop_front() {
+                if id == end {
+                    return Ok(true);
+                }
+                for next in self.neighbors(id) {
+                    if visited.insert(next.clone()) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+            Ok(false)
+        }
+
+        /// Lazily yields every path from `start` to `end` with at most `max_len` edges that
+        /// visits no vertex twice. The path from a vertex to itself is that vertex alone.
+        fn all_simple_paths(
+            &self,
+            start: I,
+            end: I,
+            max_len: usize,
+        ) -> Result<SimplePaths<I>, GraphError> {
+            let adjacency = Adjacency::new(self);
+            let start = adjacency.index_of(&start)?;
+            let end = adjacency.index_of(&end)?;
+            let mut on_path = vec![false; adjacency.len()];
+            on_path[start] = true;
+            Ok(SimplePaths {
+                successors: adjacency.successors(),
+                ids: adjacency.ids,
+                end,
+                max_len,
+                own_path: start == end,
+                path: if start == end {
+                    Vec::new()
+                } else {
+                    vec![(start, 0)]
+                },
+                on_path,
+            })
+        }
+    }
+
+    impl<T, V, I: VertexId, G: GraphView<T, V, I>> Traversal<T, V, I> for G {}
+
+    /// Iterator over simple paths, see [`Traversal::all_simple_paths`]. Works on a copy of
+    /// the graph structure taken when it was created.
+    pub struct SimplePaths<I> {
+        ids: Vec<I>,
+        successors: Vec<Vec<usize>>,
+        end: usize,
+        max_len: usize,
+        /// Whether the path from `end` to itself is still to be yielded.
+        own_path: bool,
+        /// Vertexes of the current path with the position of the next successor to try.
+        path: Vec<(usize, usize)>,
+        on_path: Vec<bool>,
+    }
+
+    impl<I: Clone> Iterator for SimplePaths<I> {
+        type Item = Vec<I>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if std::mem::take(&mut self.own_path) {
+                return Some(vec![self.ids[self.end].clone()]);
+            }
+            while let Some((vertex, position)) = self.path.last_mut() {
+                let vertex = *vertex;
+                let next = self.successors[vertex].get(*position).copied();
+                *position += 1;
+                match next {
+                    Some(next) if next == self.end && self.path.len() <= self.max_len => {
+                        return Some(
+                            self.path
+                                .iter()
+                                .map(|&(vertex, _)| vertex)
+                                .chain([next])
+                                .map(|vertex| self.ids[vertex].clone())
+                                .collect(),
+                        );
+                    }
+                    // Going further only pays off if an edge to `end` still fits.
+                    Some(next) if !self.on_path[next] && self.path.len() < self.max_len => {
+                        self.on_path[next] = true;
+                        self.path.push((next, 0));
+                    }
+                    Some(_) => {}
+                    None => {
+                        self.on_path[vertex] = false;
+                        self.path.pop();
+                    }
+                }
+            }
+            None
+        }
+    }
+}
@@ -0,0 +1,66 @@
+pub mod union_find {
+    /// Disjoint sets over the elements `0..len()`, with union by size and path halving.
+    #[derive(Debug, Clone)]
+    pub struct UnionFind {
+        parent: Vec<usize>,
+        size: Vec<usize>,
+        set_count: usize,
+    }
+
+    impl UnionFind {
+        /// Puts every element in a set of its own.
+        pub fn new(len: usize) -> Self {
+            Self {
+                parent: (0..len).collect(),
+                size: vec![1; len],
+                set_count: len,
+            }
+        }
+
+        pub fn len(&self) -> usize {
+            self.parent.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.parent.is_empty()
+        }
+
+        pub fn set_count(&self) -> usize {
+            self.set_count
+        }
+
+        /// Representative of the set containing `element`.
+        pub fn find(&mut self, mut element: usize) -> usize {
+            while self.parent[element] != element {
+                self.parent[element] = self.parent[self.parent[element]];
+                element = self.parent[element];
+            }
+            element
+        }
+
+        /// Merges the sets of `first` and `second`. Returns `false` if they were already one set.
+        pub fn union(&mut self, first: usize, second: usize) -> bool {
+            let (mut first, mut second) = (self.find(first), self.find(second));
+            if first == second {
+                return false;
+            }
+            if self.size[first] < self.size[second] {
+                std::mem::swap(&mut first, &mut second);
+            }
+            self.parent[second] = first;
+            self.size[first] += self.size[second];
+            self.set_count -= 1;
+            true
+        }
+
+        pub fn connected(&mut self, first: usize, second: usize) -> bool {
+            self.find(first) == self.find(second)
+        }
+
+        /// Number of elements in the set containing `element`.
+        pub fn set_size(&mut self, element: usize) -> usize {
+            let root = self.find(element);
+            self.size[root]
+        }
+    }
+}
@@ -1,54 +1,158 @@
 pub mod vertex {
     use std::cell::RefCell;
+    use std::collections::BTreeSet;
     use std::fmt::Debug;
+    use std::hash::Hash;
     use std::rc::Rc;
+    use std::str::FromStr;
 
     use crate::edge::edge::{DefaultEdge, OrientedEdge};
+    use crate::error::GraphError;
 
-    pub trait DefaultVertex<T, V> {
-        type EdgeType: DefaultEdge<T, V>;
+    /// Type vertexes are identified by.
+    ///
+    /// Numeric ids also report their position in the sequence `insert_vertex` allocates
+    /// from, so the graph never hands out an id that is already taken.
+    ///
+    /// Implemented for the integer types and `String`, and for `uuid::Uuid` with the `uuid` feature.
+    pub trait VertexId: Eq + Hash + Clone + FromStr + ToString {
+        fn index(&self) -> Option<usize> {
+            None
+        }
+    }
+
+    /// Vertex id that can be allocated automatically by `insert_vertex`.
+    pub trait SequentialId: VertexId {
+        /// Id at position `index` of the sequence, or `None` if the type can't represent it.
+        fn from_index(index: usize) -> Option<Self>;
+    }
+
+    macro_rules! sequential_id {
+        ($($id:ty),*) => {$(
+            impl VertexId for $id {
+                fn index(&self) -> Option<usize> {
+                    usize::try_from(*self).ok()
+                }
+            }
+
+            impl SequentialId for $id {
+                fn from_index(index: usize) -> Option<Self> {
+                    <$id>::try_from(index).ok()
+                }
+            }
+        )*};
+    }
+
+    sequential_id!(usize, u8, u16, u32, u64, u128);
 
-        fn get_edges(&self) -> Vec<Rc<RefCell<Self::EdgeType>>>;
-        fn add_neighbor(&mut self, new_neighbor: Rc<RefCell<Self::EdgeType>>);
+    impl VertexId for i8 {}
+    impl VertexId for i16 {}
+    impl VertexId for i32 {}
+    impl VertexId for i64 {}
+    impl VertexId for isize {}
+    impl VertexId for String {}
 
-        fn id(&self) -> usize;
+    #[cfg(feature = "uuid")]
+    impl VertexId for uuid::Uuid {}
+
+    /// Positions of the vertex id sequence `insert_vertex` allocates from.
+    #[derive(Debug, Clone)]
+    pub(crate) struct IdAllocator {
+        /// `None` once the position after the largest one in use doesn't fit into `usize`.
+        next: Option<usize>,
+        free: BTreeSet<usize>,
+    }
+
+    impl Default for IdAllocator {
+        fn default() -> Self {
+            Self {
+                next: Some(0),
+                free: BTreeSet::new(),
+            }
+        }
+    }
+
+    impl IdAllocator {
+        /// Smallest free position, preferring released ones.
+        pub(crate) fn next(&self) -> Result<usize, GraphError> {
+            self.free
+                .first()
+                .copied()
+                .or(self.next)
+                .ok_or(GraphError::VertexIdsExhaustedError)
+        }
+
+        pub(crate) fn reserve(&mut self, index: usize) {
+            self.free.remove(&index);
+            if self.next.is_some_and(|next| index >= next) {
+                self.next = index.checked_add(1);
+            }
+        }
+
+        /// Makes `index` available to `next` again.
+        pub(crate) fn release(&mut self, index: usize) {
+            self.free.insert(index);
+        }
+    }
+
+    pub trait DefaultVertex<T, V, I = usize> {
+        fn id(&self) -> I;
         fn value(&self) -> &T;
+        fn value_mut(&mut self) -> &mut T;
+        fn set_value(&mut self, value: T);
+
+        /// Ids of every edge that starts or ends in this vertex.
+        fn edge_ids(&self) -> impl Iterator<Item = usize>;
     }
 
     #[derive(Debug, Clone)]
-    pub struct Vertex<T: Debug, V: Debug> {
-        id: usize,
+    pub struct Vertex<T: Debug, V: Debug, I: VertexId = usize> {
+        id: I,
         value: T,
-        edges: Vec<Rc<RefCell<OrientedEdge<T, V>>>>,
+        pub(crate) edges: Vec<Rc<RefCell<OrientedEdge<T, V, I>>>>,
     }
 
-    impl<T: Debug, V: Debug> Vertex<T, V> {
-        pub(crate) fn new(id: usize, value: T) -> Self {
+    impl<T: Debug, V: Debug, I: VertexId> Vertex<T, V, I> {
+        pub(crate) fn new(id: I, value: T) -> Self {
             Self {
                 id,
                 value,
                 edges: Vec::default(),
             }
         }
-    }
-
-    impl<T: Debug, V: Debug> DefaultVertex<T, V> for Vertex<T, V> {
-        type EdgeType = OrientedEdge<T, V>;
 
-        fn get_edges(&self) -> Vec<Rc<RefCell<Self::EdgeType>>> {
-            self.edges.clone()
+        pub fn edges(&self) -> impl Iterator<Item = &Rc<RefCell<OrientedEdge<T, V, I>>>> {
+            self.edges.iter()
         }
 
-        fn add_neighbor(&mut self, new_neighbor: Rc<RefCell<Self::EdgeType>>) {
+        pub(crate) fn add_neighbor(&mut self, new_neighbor: Rc<RefCell<OrientedEdge<T, V, I>>>) {
             self.edges.push(new_neighbor)
         }
 
-        fn id(&self) -> usize {
-            self.id
+        pub(crate) fn remove_neighbor(&mut self, edge: &Rc<RefCell<OrientedEdge<T, V, I>>>) {
+            self.edges.retain(|e| !Rc::ptr_eq(e, edge))
+        }
+    }
+
+    impl<T: Debug, V: Debug, I: VertexId> DefaultVertex<T, V, I> for Vertex<T, V, I> {
+        fn id(&self) -> I {
+            self.id.clone()
         }
 
         fn value(&self) -> &T {
             &self.value
         }
+
+        fn value_mut(&mut self) -> &mut T {
+            &mut self.value
+        }
+
+        fn set_value(&mut self, value: T) {
+            self.value = value
+        }
+
+        fn edge_ids(&self) -> impl Iterator<Item = usize> {
+            self.edges.iter().filter_map(|edge| edge.borrow().id())
+        }
     }
 }
@@ -0,0 +1,33 @@
+pub mod weight {
+    use std::cmp::Ordering;
+    use std::fmt::Debug;
+    use std::ops::{Add, Sub};
+
+    /// Numeric type edge weights, capacities and costs are measured in.
+    /// `Default::default()` is used as zero.
+    pub trait Weight:
+        Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Default + Debug
+    {
+    }
+
+    impl<W> Weight for W where W: Copy + PartialOrd + Add<Output = W> + Sub<Output = W> + Default + Debug
+    {}
+
+    /// Orders weights for use in heaps; incomparable values such as NaN count as equal.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub(crate) struct Ordered<W>(pub(crate) W);
+
+    impl<W: PartialOrd> Eq for Ordered<W> {}
+
+    impl<W: PartialOrd> PartialOrd for Ordered<W> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<W: PartialOrd> Ord for Ordered<W> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use graph_lib::analytics::analytics::Analytics;
+#[cfg(feature = "parallel")]
+use graph_lib::arena::arena::ArenaGraph;
+use graph_lib::error::GraphError;
+use graph_lib::graph::graph::{DefaultGraph, OrientedGraph};
+#[cfg(feature = "parallel")]
+use graph_lib::parallel::parallel::ParallelAnalytics;
+use graph_lib::serde::serde_graph::DeserializeGraph;
+#[cfg(feature = "parallel")]
+use graph_lib::shared::shared::SharedGraph;
+use graph_lib::traversal::traversal::Traversal;
+
+#[cfg(feature = "parallel")]
+use crate::support::random_graph;
+
+const WEIGHTED_STR: &str = concat!(
+    "1 A\n", "2 B\n", "3 C\n", "4 D\n", "5 E\n", "6 F\n", "#\n", "1 2 7\n", "1 3 2\n", "3 2 3\n",
+    "2 4 1\n", "5 6 4"
+);
+
+#[test]
+fn shortest_path_lengths() {
+    let graph = OrientedGraph::<String, u32>::deserialize(WEIGHTED_STR).unwrap();
+    let lengths = graph.shortest_path_lengths(1, |&weight| weight).unwrap();
+    assert_eq!(lengths.len(), 4);
+    assert_eq!(lengths[&2], 5);
+    assert_eq!(lengths[&4], 6);
+    assert!(!lengths.contains_key(&5));
+
+    let negative = OrientedGraph::<String, i32>::deserialize("1 A\n2 B\n#\n1 2 -1").unwrap();
+    assert!(matches!(
+        negative.shortest_path_lengths(1, |&weight| weight),
+        Err(GraphError::NegativeWeightError)
+    ));
+}
+
+#[test]
+fn components_and_page_rank() {
+    let graph = OrientedGraph::<String, u32>::deserialize(WEIGHTED_STR).unwrap();
+    let components = graph.weakly_connected_components();
+    assert_eq!(
+        (1..=6).map(|id| components[&id]).collect::<Vec<_>>(),
+        vec![0, 0, 0, 0, 1, 1]
+    );
+
+    let ranks = graph.page_rank(0.85, 50);
+    assert!((ranks.values().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!(ranks[&4] > ranks[&2] && ranks[&2] > ranks[&1]);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_matches_sequential() {
+    let graph = random_graph();
+    let csr = graph.freeze();
+    let shared = SharedGraph::from(graph.clone());
+
+    for start in [0, 17, 120, 299] {
+        let expected = graph.breadth_first_order(start).unwrap();
+        assert_eq!(csr.par_breadth_first_order(start).unwrap(), expected);
+        assert_eq!(shared.par_breadth_first_order(start).unwrap(), expected);
+    }
+    assert_eq!(
+        csr.par_weakly_connected_components(),
+        graph.weakly_connected_components()
+    );
+    assert_eq!(csr.par_page_rank(0.85, 30), graph.page_rank(0.85, 30));
+    assert_eq!(
+        shared
+            .par_all_shortest_path_lengths(|&weight| weight)
+            .unwrap(),
+        csr.all_shortest_path_lengths(|&weight| weight).unwrap()
+    );
+    assert!(csr.par_breadth_first_order(300).is_err());
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_components_on_long_chain() {
+    let mut graph = ArenaGraph::<usize, u32>::default();
+    for id in 0..100_000 {
+        graph.add_raw_vertex(id, id).unwrap();
+    }
+    for id in 1..100_000 {
+        if id % 25_000 != 0 {
+            graph.add_edge_with_vertex_id(id, id - 1, None).unwrap();
+        }
+    }
+    let components = graph.par_weakly_connected_components();
+    assert_eq!(components, graph.weakly_connected_components());
+    assert_eq!(components[&99_999], 3);
+}
+
+#[test]
+fn islands_after_vertex_removal() {
+    let mut graph = OrientedGraph::<String, u32>::deserialize(WEIGHTED_STR).unwrap();
+    assert!(graph.is_reachable(1, 4).unwrap());
+    assert!(!graph.is_reachable(4, 1).unwrap());
+    assert!(graph.is_reachable(5, 5).unwrap());
+    assert!(graph.is_reachable(1, 7).is_err());
+    assert_eq!(graph.reachable_from(3).unwrap(), HashSet::from([3, 2, 4]));
+
+    assert!(graph.remove_vertex_by_id(2).is_ok());
+    let components = graph.weakly_connected_components();
+    let islands: HashSet<usize> = components.values().copied().collect();
+    assert_eq!(islands.len(), 3);
+    assert_eq!(components[&1], components[&3]);
+    assert_ne!(components[&1], components[&4]);
+    assert!(!graph.is_reachable(1, 4).unwrap());
+}
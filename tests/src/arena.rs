@@ -0,0 +1,111 @@
+use graph_lib::arena::arena::ArenaGraph;
+use graph_lib::edge::edge::DefaultEdge;
+use graph_lib::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::DeserializeGraph;
+use graph_lib::vertex::vertex::DefaultVertex;
+
+use crate::support::{snapshot, workload, GRAPH_STR};
+
+#[test]
+fn deserialization_matches() {
+    let oriented = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    let arena = ArenaGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    assert_eq!(arena.vertex_count(), oriented.vertex_count());
+    assert_eq!(arena.edges_count(), oriented.edges_count());
+    assert_eq!(snapshot(&arena), snapshot(&oriented));
+    assert!(arena.get_vertex_by_id(1).is_some());
+    assert!(arena.get_vertex_by_id(5).is_none());
+}
+
+#[test]
+fn workload_matches_oriented_graph() {
+    let oriented = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    let arena = ArenaGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    assert_eq!(workload(arena), workload(oriented));
+}
+
+#[test]
+fn multigraph_workload_matches_oriented_graph() {
+    let policy = GraphPolicy {
+        reuse_vertex_ids: true,
+        ..GraphPolicy::multigraph()
+    };
+    let oriented =
+        OrientedGraph::<String, String>::deserialize_with_policy(GRAPH_STR, policy).unwrap();
+    let arena = ArenaGraph::<String, String>::deserialize_with_policy(GRAPH_STR, policy).unwrap();
+    assert_eq!(workload(arena), workload(oriented));
+}
+
+#[test]
+fn arena_edges_keep_ids() {
+    let mut arena = ArenaGraph::<String, String>::multigraph();
+    arena.add_raw_vertex(1, "First".to_string()).unwrap();
+    arena.add_raw_vertex(2, "Second".to_string()).unwrap();
+    let first = arena.add_edge_with_vertex_id(1, 2, None).unwrap();
+    let second = arena.add_edge_with_vertex_id(1, 2, None).unwrap();
+    assert!(arena.remove_edge_by_id(first).is_ok());
+
+    let edge = arena.get_edge_by_id(second).unwrap();
+    assert_eq!(edge.id(), Some(second));
+    assert_eq!(
+        arena
+            .get_vertex_by_id(2)
+            .unwrap()
+            .edge_ids()
+            .collect::<Vec<_>>(),
+        vec![second]
+    );
+}
+
+#[test]
+fn arena_rejects_last_edge_id() {
+    let graph = concat!(
+        "1 First vertex\n",
+        "2 Second vertex\n",
+        "#\n",
+        "18446744073709551615: 1 2 x"
+    );
+    assert!(ArenaGraph::<String, String>::deserialize(graph).is_err());
+}
+
+#[test]
+fn arena_insert_vertex_after_last_id() {
+    let mut arena = ArenaGraph::<String, String>::default();
+    assert!(arena.add_raw_vertex(usize::MAX, "Last".to_string()).is_ok());
+    assert!(arena.insert_vertex("Overflow".to_string()).is_err());
+    assert_eq!(arena.vertex_count(), 1);
+}
+
+/// Adds and removes vertexes and edges in rounds, leaving one vertex of each round behind.
+fn churn<G: DefaultGraph<String, String>>(graph: &mut G) {
+    for round in 0..50 {
+        let kept = graph.insert_vertex(format!("Kept {round}")).unwrap();
+        let dropped = graph.insert_vertex(format!("Dropped {round}")).unwrap();
+        graph.add_edge_with_vertex_id(1, kept, None).unwrap();
+        let edge = graph.add_edge_with_vertex_id(kept, dropped, None).unwrap();
+        graph.add_edge_with_vertex_id(dropped, 2, None).unwrap();
+        graph.remove_edge_by_id(edge).unwrap();
+        graph.remove_vertex_by_id(dropped).unwrap();
+    }
+}
+
+#[test]
+fn arena_compaction_keeps_order() {
+    let mut oriented = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    let mut arena = ArenaGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    churn(&mut oriented);
+    churn(&mut arena);
+    assert_eq!(snapshot(&arena), snapshot(&oriented));
+
+    arena.shrink_to_fit();
+    assert_eq!(snapshot(&arena), snapshot(&oriented));
+    assert!(arena.remove_vertex_by_id(1).is_ok());
+    assert!(oriented.remove_vertex_by_id(1).is_ok());
+    assert_eq!(snapshot(&arena), snapshot(&oriented));
+}
+
+#[test]
+fn arena_is_send_and_sync() {
+    fn assert_send_sync<G: Send + Sync>() {}
+    assert_send_sync::<ArenaGraph<String, String>>();
+}
@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+
+use graph_lib::connectivity::connectivity::Connectivity;
+use graph_lib::edge::edge::DefaultEdge;
+use graph_lib::graph::graph::{GraphPolicy, GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::DeserializeGraph;
+use graph_lib::traversal::traversal::Traversal;
+
+use crate::support::{multigraph_without, Rng};
+
+const TOPOLOGY_STR: &str = concat!(
+    "1 core-a\n",
+    "2 core-b\n",
+    "3 core-c\n",
+    "4 edge-a\n",
+    "5 edge-b\n",
+    "6 edge-c\n",
+    "7 branch\n",
+    "8 backup\n",
+    "#\n",
+    "1 2 10\n",
+    "2 3 10\n",
+    "3 1 10\n",
+    "3 4 1\n",
+    "4 5 1\n",
+    "5 6 1\n",
+    "6 4 1\n",
+    "6 7 1\n",
+    "7 8 1\n",
+    "8 7 1\n",
+    "5 5 0"
+);
+
+#[test]
+fn single_points_of_failure() {
+    let graph = OrientedGraph::<String, u32>::deserialize_with_policy(
+        TOPOLOGY_STR,
+        GraphPolicy::multigraph(),
+    )
+    .unwrap();
+    let edge_ids = |edges: &[(usize, usize)]| -> HashSet<usize> {
+        edges
+            .iter()
+            .map(|&(start, end)| {
+                graph
+                    .get_edge_by_vertexes_id(start, end)
+                    .unwrap()
+                    .id()
+                    .unwrap()
+            })
+            .collect()
+    };
+
+    let bridges: HashSet<usize> = graph.bridges().into_iter().collect();
+    assert_eq!(bridges, edge_ids(&[(3, 4), (6, 7)]));
+    assert_eq!(graph.articulation_points(), vec![3, 4, 6, 7]);
+
+    let components: HashSet<Vec<usize>> = graph
+        .biconnected_components()
+        .into_iter()
+        .map(|mut component| {
+            component.sort_unstable();
+            component
+        })
+        .collect();
+    let expected: HashSet<Vec<usize>> = [
+        &[(1, 2), (2, 3), (3, 1)][..],
+        &[(3, 4)],
+        &[(4, 5), (5, 6), (6, 4)],
+        &[(6, 7)],
+        &[(7, 8), (8, 7)],
+    ]
+    .into_iter()
+    .map(|edges| {
+        let mut ids: Vec<usize> = edge_ids(edges).into_iter().collect();
+        ids.sort_unstable();
+        ids
+    })
+    .collect();
+    assert_eq!(components, expected);
+}
+
+fn component_count(graph: &OrientedGraph<String, u32>) -> usize {
+    graph
+        .weakly_connected_components()
+        .into_values()
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+#[test]
+fn matches_brute_force() {
+    let mut rng = Rng::new(17);
+    for _ in 0..10 {
+        let edges = rng.pairs(18, 22);
+        let graph = multigraph_without(18, &edges, None, None);
+        let components = component_count(&graph);
+
+        // Edge ids are given out in insertion order, starting from zero.
+        let bridges: HashSet<usize> = graph.bridges().into_iter().collect();
+        for index in 0..edges.len() {
+            let split =
+                component_count(&multigraph_without(18, &edges, None, Some(index))) > components;
+            assert_eq!(bridges.contains(&index), split);
+        }
+        let articulation: HashSet<usize> = graph.articulation_points().into_iter().collect();
+        for vertex in 0..18 {
+            let remaining = component_count(&multigraph_without(18, &edges, Some(vertex), None));
+            assert_eq!(articulation.contains(&vertex), remaining > components);
+        }
+
+        let covered: Vec<usize> = graph
+            .biconnected_components()
+            .into_iter()
+            .flatten()
+            .collect();
+        let loops = edges.iter().filter(|&&(start, end)| start == end).count();
+        assert_eq!(covered.len(), edges.len() - loops);
+        assert_eq!(covered.iter().collect::<HashSet<_>>().len(), covered.len());
+    }
+}
@@ -0,0 +1,115 @@
+use graph_lib::csr::csr::CsrGraph;
+use graph_lib::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::{DeserializeGraph, SerializeGraph};
+use graph_lib::traversal::traversal::Traversal;
+use graph_lib::vertex::vertex::DefaultVertex;
+
+use crate::support::snapshot;
+
+const TREE_STR: &str = concat!(
+    "1 Root\n",
+    "2 Left\n",
+    "3 Right\n",
+    "4 Leaf\n",
+    "5 Isolated\n",
+    "#\n",
+    "0: 1 2 1-2\n",
+    "1: 1 3 1-3\n",
+    "2: 2 4 2-4\n",
+    "3: 3 4 3-4\n",
+    "4: 4 1 4-1"
+);
+
+fn frozen() -> CsrGraph<String, String> {
+    OrientedGraph::<String, String>::deserialize(TREE_STR)
+        .unwrap()
+        .freeze()
+}
+
+#[test]
+fn freeze_keeps_graph() {
+    let mut graph = OrientedGraph::<String, String>::deserialize(TREE_STR).unwrap();
+    assert!(graph.remove_edge_by_id(1).is_ok());
+    assert!(graph.add_edge_with_vertex_id(5, 5, None).is_ok());
+    let csr = graph.freeze();
+
+    assert_eq!(snapshot(&csr), snapshot(&graph));
+    assert_eq!(
+        csr.get_vertex_by_id(4)
+            .unwrap()
+            .edge_ids()
+            .collect::<Vec<_>>(),
+        graph
+            .get_vertex_by_id(4)
+            .unwrap()
+            .edge_ids()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(csr.depth_first_order(1).unwrap(), vec![1, 2, 4]);
+}
+
+#[test]
+fn csr_layout() {
+    let csr = frozen();
+    assert_eq!(csr.offsets(), &[0, 2, 3, 4, 5, 5]);
+    assert_eq!(csr.targets(), &[1, 2, 3, 3, 0]);
+    assert_eq!(csr.index_of(&4), Some(3));
+    assert_eq!(csr.id_of(3), &4);
+    assert_eq!(csr.index_of(&6), None);
+    assert_eq!(csr.out_edges_at(0).len(), 2);
+}
+
+#[test]
+fn thaw_restores_mutable_graph() {
+    let source = OrientedGraph::<String, String>::deserialize_with_policy(
+        TREE_STR,
+        GraphPolicy::multigraph(),
+    )
+    .unwrap();
+    let mut graph = source.freeze().thaw();
+    assert_eq!(graph.serialize().unwrap(), source.serialize().unwrap());
+    assert_eq!(graph.policy(), GraphPolicy::multigraph());
+    assert_eq!(graph.add_edge_with_vertex_id(1, 2, None).unwrap(), 5);
+}
+
+#[test]
+fn thaw_keeps_removed_ids_retired() {
+    let mut source = OrientedGraph::<String, String>::deserialize(TREE_STR).unwrap();
+    assert!(source.remove_vertex_by_id(5).is_ok());
+    assert!(source.remove_edge_by_id(4).is_ok());
+    let mut graph = source.freeze().thaw();
+    assert_eq!(graph.insert_vertex("New".to_string()).unwrap(), 6);
+    assert_eq!(graph.add_edge_with_vertex_id(4, 1, None).unwrap(), 5);
+
+    let mut source = OrientedGraph::<String, String>::with_policy(GraphPolicy {
+        reuse_vertex_ids: true,
+        ..GraphPolicy::default()
+    });
+    for value in ["First", "Second", "Third"] {
+        source.insert_vertex(value.to_string()).unwrap();
+    }
+    assert!(source.remove_vertex_by_id(1).is_ok());
+    let mut graph = source.freeze().thaw();
+    assert_eq!(graph.insert_vertex("Reused".to_string()).unwrap(), 1);
+    assert_eq!(graph.insert_vertex("Fresh".to_string()).unwrap(), 3);
+}
+
+#[test]
+fn traversals_match_across_representations() {
+    let oriented = OrientedGraph::<String, String>::deserialize(TREE_STR).unwrap();
+    let csr = oriented.freeze();
+
+    let dfs = oriented.depth_first_search(1).unwrap().to_string();
+    let expected: Vec<usize> = dfs
+        .lines()
+        .map(|line| line.split(' ').next().unwrap().parse().unwrap())
+        .collect();
+    assert_eq!(oriented.depth_first_order(1).unwrap(), expected);
+    assert_eq!(csr.depth_first_order(1).unwrap(), expected);
+    assert_eq!(csr.breadth_first_order(1).unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(
+        oriented.breadth_first_order(3).unwrap(),
+        csr.breadth_first_order(3).unwrap()
+    );
+    assert!(csr.depth_first_order(6).is_err());
+}
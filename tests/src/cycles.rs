@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use graph_lib::cycles::cycles::Cycles;
+use graph_lib::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::DeserializeGraph;
+use graph_lib::vertex::vertex::DefaultVertex;
+
+use crate::support::Rng;
+
+const DEPENDENCIES_STR: &str = concat!(
+    "1 app\n",
+    "2 core\n",
+    "3 io\n",
+    "4 utils\n",
+    "#\n",
+    "1 2 uses\n",
+    "2 3 uses\n",
+    "3 4 uses\n",
+    "2 4 uses"
+);
+
+fn is_cycle(graph: &OrientedGraph<String, String>, cycle: &[usize]) -> bool {
+    !cycle.is_empty()
+        && cycle
+            .iter()
+            .zip(cycle.iter().cycle().skip(1))
+            .all(|(&start, &end)| graph.get_edge_by_vertexes_id(start, end).is_some())
+}
+
+#[test]
+fn find_cycle() {
+    let mut graph = OrientedGraph::<String, String>::deserialize(DEPENDENCIES_STR).unwrap();
+    assert_eq!(graph.find_cycle(), None);
+
+    assert!(graph.add_edge_with_vertex_id(4, 2, None).is_ok());
+    let cycle = graph.find_cycle().unwrap();
+    assert!(is_cycle(&graph, &cycle));
+    assert!(!cycle.contains(&1));
+
+    assert!(graph.remove_vertex_by_id(2).is_ok());
+    assert!(graph.add_edge_with_vertex_id(1, 1, None).is_ok());
+    assert_eq!(graph.find_cycle(), Some(vec![1]));
+}
+
+#[test]
+fn elementary_cycles() {
+    let mut graph = OrientedGraph::<String, String>::with_policy(GraphPolicy::multigraph());
+    for id in 1..=3 {
+        graph.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    for start in 1..=3 {
+        for end in 1..=3 {
+            if start != end {
+                graph.add_edge_with_vertex_id(start, end, None).unwrap();
+            }
+        }
+    }
+    // A parallel edge doesn't make a new cycle of vertexes.
+    graph.add_edge_with_vertex_id(1, 2, None).unwrap();
+    graph.add_edge_with_vertex_id(3, 3, None).unwrap();
+
+    let cycles = graph.all_elementary_cycles(usize::MAX);
+    assert_eq!(cycles.len(), 6);
+    assert!(cycles.iter().all(|cycle| is_cycle(&graph, cycle)));
+    let distinct: HashSet<Vec<usize>> = cycles.iter().cloned().collect();
+    assert_eq!(distinct.len(), 6);
+    assert!(distinct.contains(&vec![1, 2, 3]));
+    assert!(distinct.contains(&vec![1, 3, 2]));
+    assert!(distinct.contains(&vec![3]));
+
+    assert_eq!(graph.all_elementary_cycles(4).len(), 4);
+    let acyclic = OrientedGraph::<String, String>::deserialize(DEPENDENCIES_STR).unwrap();
+    assert!(acyclic.all_elementary_cycles(10).is_empty());
+}
+
+/// Counts elementary cycles by extending every path from its smallest vertex.
+fn brute_force_cycles(graph: &OrientedGraph<String, String>) -> usize {
+    fn extend(graph: &OrientedGraph<String, String>, path: &mut Vec<usize>, count: &mut usize) {
+        let last = *path.last().unwrap();
+        let mut next: Vec<usize> = graph.neighbors(last).collect();
+        next.sort_unstable();
+        next.dedup();
+        for next in next {
+            if next == path[0] {
+                *count += 1;
+            } else if next > path[0] && !path.contains(&next) {
+                path.push(next);
+                extend(graph, path, count);
+                path.pop();
+            }
+        }
+    }
+    let mut count = 0;
+    for vertex in graph.vertices().map(|vertex| vertex.id()) {
+        extend(graph, &mut vec![vertex], &mut count);
+    }
+    count
+}
+
+#[test]
+fn elementary_cycles_match_brute_force() {
+    let mut graph = OrientedGraph::<String, String>::with_policy(GraphPolicy::multigraph());
+    for id in 0..9 {
+        graph.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    for (start, end) in Rng::new(3).pairs(9, 30) {
+        graph.add_edge_with_vertex_id(start, end, None).unwrap();
+    }
+    let cycles = graph.all_elementary_cycles(usize::MAX);
+    assert_eq!(cycles.len(), brute_force_cycles(&graph));
+    assert!(cycles.iter().all(|cycle| is_cycle(&graph, cycle)));
+}
@@ -0,0 +1,142 @@
+use std::collections::{HashMap, HashSet};
+
+use graph_lib::dominators::dominators::Dominance;
+use graph_lib::error::GraphError;
+use graph_lib::graph::graph::OrientedGraph;
+use graph_lib::serde::serde_graph::DeserializeGraph;
+use graph_lib::traversal::traversal::Traversal;
+
+use crate::support::{multigraph_without, Rng};
+
+/// Flow graph from the Lengauer–Tarjan paper, with R = 1, A = 2, ..., L = 13.
+const FLOW_STR: &str = concat!(
+    "1 R\n",
+    "2 A\n",
+    "3 B\n",
+    "4 C\n",
+    "5 D\n",
+    "6 E\n",
+    "7 F\n",
+    "8 G\n",
+    "9 H\n",
+    "10 I\n",
+    "11 J\n",
+    "12 K\n",
+    "13 L\n",
+    "14 dead\n",
+    "#\n",
+    "1 2 _\n",
+    "1 3 _\n",
+    "1 4 _\n",
+    "2 5 _\n",
+    "3 2 _\n",
+    "3 5 _\n",
+    "3 6 _\n",
+    "4 7 _\n",
+    "4 8 _\n",
+    "5 13 _\n",
+    "6 9 _\n",
+    "7 10 _\n",
+    "8 10 _\n",
+    "8 11 _\n",
+    "9 6 _\n",
+    "9 12 _\n",
+    "10 12 _\n",
+    "11 10 _\n",
+    "12 10 _\n",
+    "12 1 _\n",
+    "13 9 _\n",
+    "14 1 _"
+);
+
+#[test]
+fn lengauer_tarjan_example() {
+    let graph = OrientedGraph::<String, String>::deserialize(FLOW_STR).unwrap();
+    let dominators = graph.dominators(1).unwrap();
+    let expected: HashMap<usize, usize> = HashMap::from([
+        (2, 1),
+        (3, 1),
+        (4, 1),
+        (5, 1),
+        (6, 1),
+        (7, 4),
+        (8, 4),
+        (9, 1),
+        (10, 1),
+        (11, 8),
+        (12, 1),
+        (13, 5),
+    ]);
+    assert_eq!(dominators.immediate_dominators(), &expected);
+    assert_eq!(dominators.root(), &1);
+    assert_eq!(dominators.immediate_dominator(&1), None);
+    assert_eq!(dominators.immediate_dominator(&14), None);
+    assert_eq!(dominators.dominators_of(&11), Some(vec![11, 8, 4, 1]));
+    assert!(dominators.dominates(&4, &7));
+    assert!(!dominators.dominates(&3, &6));
+
+    assert_eq!(
+        dominators.dominance_frontier(&4),
+        Some(&HashSet::from([10]))
+    );
+    assert_eq!(
+        dominators.dominance_frontier(&8),
+        Some(&HashSet::from([10]))
+    );
+    assert_eq!(dominators.dominance_frontier(&6), Some(&HashSet::from([9])));
+    assert_eq!(dominators.dominance_frontier(&1), Some(&HashSet::from([1])));
+    assert_eq!(dominators.dominance_frontier(&14), None);
+
+    assert!(matches!(
+        graph.dominators(15),
+        Err(GraphError::VertexNotFound)
+    ));
+}
+
+#[test]
+fn matches_brute_force() {
+    let mut rng = Rng::new(29);
+    for _ in 0..10 {
+        let edges = rng.pairs(16, 30);
+        let graph = multigraph_without(16, &edges, None, None);
+        let reachable = graph.reachable_from(0).unwrap();
+        let dominators = graph.dominators(0).unwrap();
+
+        // `dominator` dominates `vertex` if removing it cuts `vertex` off the root.
+        let mut dominated_by: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for &dominator in &reachable {
+            let without = multigraph_without(16, &edges, Some(dominator), None);
+            let still = if dominator == 0 {
+                HashSet::new()
+            } else {
+                without.reachable_from(0).unwrap()
+            };
+            for &vertex in &reachable {
+                if vertex == dominator || !still.contains(&vertex) {
+                    dominated_by.entry(vertex).or_default().insert(dominator);
+                }
+            }
+        }
+        for vertex in 0..16 {
+            let expected = dominated_by.get(&vertex);
+            let found: Option<HashSet<usize>> = dominators
+                .dominators_of(&vertex)
+                .map(|dominators| dominators.into_iter().collect());
+            assert_eq!(found.as_ref(), expected);
+        }
+
+        for &vertex in &reachable {
+            let strictly = |end: usize| end != vertex && dominated_by[&end].contains(&vertex);
+            let expected: HashSet<usize> = edges
+                .iter()
+                .filter(|&&(start, end)| {
+                    reachable.contains(&start)
+                        && dominated_by[&start].contains(&vertex)
+                        && !strictly(end)
+                })
+                .map(|&(_, end)| end)
+                .collect();
+            assert_eq!(dominators.dominance_frontier(&vertex), Some(&expected));
+        }
+    }
+}
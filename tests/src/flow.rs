@@ -0,0 +1,176 @@
+use std::collections::{HashMap, HashSet};
+
+use graph_lib::edge::edge::{DefaultEdge, DefaultOrientedEdge};
+use graph_lib::error::GraphError;
+use graph_lib::flow::flow::{Flow, MaxFlow};
+use graph_lib::graph::graph::{DefaultGraph, GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::DeserializeGraph;
+
+use crate::support::random_graph;
+
+const NETWORK_STR: &str = concat!(
+    "1 s\n", "2 v1\n", "3 v2\n", "4 v3\n", "5 v4\n", "6 t\n", "#\n", "1 2 16\n", "1 3 13\n",
+    "3 2 4\n", "2 4 12\n", "4 3 9\n", "3 5 14\n", "5 4 7\n", "4 6 20\n", "5 6 4\n", "6 6 10"
+);
+
+/// Checks capacity limits, conservation and that the cut matches the flow value.
+fn check_flow<T, G: GraphView<T, u32>>(
+    graph: &G,
+    flow: &MaxFlow<usize, u32>,
+    source: usize,
+    sink: usize,
+) {
+    let mut balance: HashMap<usize, i64> = HashMap::new();
+    for edge in graph.edges() {
+        let id = edge.id().unwrap();
+        let (start, end) = (edge.start_id().unwrap(), edge.end_id().unwrap());
+        let amount = flow.edge_flows[&id];
+        assert!(amount <= edge.value().copied().unwrap_or(0));
+        *balance.entry(start).or_default() -= amount as i64;
+        *balance.entry(end).or_default() += amount as i64;
+    }
+    for (vertex, balance) in balance {
+        match vertex {
+            _ if vertex == source => assert_eq!(balance, -(flow.value as i64)),
+            _ if vertex == sink => assert_eq!(balance, flow.value as i64),
+            _ => assert_eq!(balance, 0),
+        }
+    }
+
+    assert!(flow.source_side.contains(&source));
+    assert!(flow.sink_side.contains(&sink));
+    assert_eq!(
+        flow.source_side.len() + flow.sink_side.len(),
+        graph.vertex_count()
+    );
+    let cut: u32 = flow
+        .cut_edges
+        .iter()
+        .map(|&id| {
+            let edge = graph.get_edge_by_id(id).unwrap();
+            assert!(flow.source_side.contains(&edge.start_id().unwrap()));
+            assert!(flow.sink_side.contains(&edge.end_id().unwrap()));
+            flow.edge_flows[&id]
+        })
+        .sum();
+    assert_eq!(cut, flow.value);
+}
+
+#[test]
+fn max_flow_and_min_cut() {
+    let graph = OrientedGraph::<String, u32>::deserialize(NETWORK_STR).unwrap();
+    for flow in [
+        graph.edmonds_karp(1, 6, |&capacity| capacity).unwrap(),
+        graph.dinic(1, 6, |&capacity| capacity).unwrap(),
+    ] {
+        assert_eq!(flow.value, 23);
+        assert_eq!(flow.edge_flows.len(), graph.edges_count());
+        assert_eq!(flow.source_side, HashSet::from([1, 2, 3, 5]));
+        check_flow(&graph, &flow, 1, 6);
+    }
+
+    assert!(matches!(
+        graph.dinic(1, 1, |&capacity| capacity),
+        Err(GraphError::SameSourceAndSinkError)
+    ));
+    assert!(matches!(
+        graph.edmonds_karp(1, 7, |&capacity| capacity),
+        Err(GraphError::VertexNotFound)
+    ));
+    let unreachable = graph.dinic(6, 1, |&capacity| capacity).unwrap();
+    assert_eq!(unreachable.value, 0);
+    assert!(unreachable.cut_edges.is_empty());
+}
+
+#[test]
+fn edmonds_karp_matches_dinic() {
+    let graph = random_graph();
+    for (source, sink) in [(0, 1), (3, 200), (17, 42)] {
+        let edmonds_karp = graph
+            .edmonds_karp(source, sink, |&capacity| capacity)
+            .unwrap();
+        let dinic = graph.dinic(source, sink, |&capacity| capacity).unwrap();
+        assert_eq!(edmonds_karp.value, dinic.value);
+        assert_eq!(edmonds_karp.source_side, dinic.source_side);
+        check_flow(&graph, &edmonds_karp, source, sink);
+        check_flow(&graph, &dinic, source, sink);
+    }
+}
+
+/// Shipment network with `(capacity, cost)` edge values.
+fn shipments(routes: &[(usize, usize, (i64, i64))]) -> OrientedGraph<String, (i64, i64)> {
+    let mut graph = OrientedGraph::default();
+    for id in 1..=4 {
+        graph.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    for &(start, end, value) in routes {
+        graph
+            .add_edge_with_vertex_id(start, end, Some(value))
+            .unwrap();
+    }
+    graph
+}
+
+#[test]
+fn min_cost_max_flow() {
+    let graph = shipments(&[
+        (1, 2, (4, 2)),
+        (1, 3, (2, 2)),
+        (2, 3, (2, 1)),
+        (2, 4, (3, 3)),
+        (3, 4, (5, 1)),
+    ]);
+    let flow = graph
+        .min_cost_max_flow(1, 4, |&(capacity, _)| capacity, |&(_, cost)| cost)
+        .unwrap();
+    assert_eq!(flow.value, 6);
+    assert_eq!(flow.cost, 2 * 3 + 2 * 4 + 2 * 5);
+    let flows: HashMap<(usize, usize), i64> = graph
+        .edges()
+        .map(|edge| {
+            let ends = (edge.start_id().unwrap(), edge.end_id().unwrap());
+            (ends, flow.edge_flows[&edge.id().unwrap()])
+        })
+        .collect();
+    assert_eq!(flows[&(1, 3)], 2);
+    assert_eq!(flows[&(2, 3)], 2);
+    assert_eq!(flows[&(2, 4)], 2);
+    assert_eq!(flows[&(3, 4)], 4);
+
+    let negative = shipments(&[(1, 2, (3, -2)), (2, 4, (2, 1)), (1, 4, (4, 0))]);
+    let flow = negative
+        .min_cost_max_flow(1, 4, |&(capacity, _)| capacity, |&(_, cost)| cost)
+        .unwrap();
+    assert_eq!((flow.value, flow.cost), (6, -2));
+
+    let cycle = shipments(&[
+        (1, 2, (1, 1)),
+        (2, 3, (1, -3)),
+        (3, 2, (1, 1)),
+        (3, 4, (1, 1)),
+    ]);
+    assert!(matches!(
+        cycle.min_cost_max_flow(1, 4, |&(capacity, _)| capacity, |&(_, cost)| cost),
+        Err(GraphError::NegativeCycleError)
+    ));
+}
+
+#[test]
+fn min_cost_flow_is_maximal_and_cheaper() {
+    let graph = random_graph();
+    let cost = |&weight: &u32| i64::from(weight % 7);
+    for (source, sink) in [(0, 1), (3, 200), (17, 42)] {
+        let cheapest = graph
+            .min_cost_max_flow(source, sink, |&capacity| capacity, cost)
+            .unwrap();
+        let dinic = graph.dinic(source, sink, |&capacity| capacity).unwrap();
+        assert_eq!(cheapest.value, dinic.value);
+        let dinic_cost: i64 = graph
+            .edges()
+            .map(|edge| {
+                i64::from(dinic.edge_flows[&edge.id().unwrap()]) * cost(edge.value().unwrap())
+            })
+            .sum();
+        assert!(cheapest.cost <= dinic_cost);
+    }
+}
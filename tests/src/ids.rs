@@ -0,0 +1,161 @@
+use graph_lib::edge::edge::{DefaultEdge, DefaultOrientedEdge};
+use graph_lib::error::GraphError;
+use graph_lib::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::{DeserializeGraph, SerializeGraph};
+use graph_lib::traversal::traversal::Traversal;
+use graph_lib::vertex::vertex::DefaultVertex;
+use uuid::Uuid;
+
+const SERVICES_STR: &str = concat!(
+    "gateway Gateway\n",
+    "auth Auth\n",
+    "db Database\n",
+    "#\n",
+    "0: gateway auth calls\n",
+    "1: auth db reads\n",
+    "2: gateway db reads"
+);
+
+fn id(id: &str) -> String {
+    id.to_string()
+}
+
+#[test]
+fn string_ids() {
+    let mut graph = OrientedGraph::<String, String, String>::deserialize(SERVICES_STR).unwrap();
+    assert_eq!(graph.serialize().unwrap(), SERVICES_STR);
+    assert_eq!(graph.get_vertex_by_id(id("auth")).unwrap().value(), "Auth");
+    assert_eq!(
+        graph.get_edge_by_id(1).unwrap().start_id(),
+        Some(id("auth"))
+    );
+    assert_eq!(
+        graph.depth_first_order(id("gateway")).unwrap(),
+        vec![id("gateway"), id("auth"), id("db")]
+    );
+
+    assert!(graph.add_raw_vertex(id("cache"), id("Cache")).is_ok());
+    assert!(graph
+        .add_edge_with_vertex_id(id("gateway"), id("cache"), None)
+        .is_ok());
+    assert!(graph.remove_vertex_by_id(id("db")).is_ok());
+    assert_eq!(
+        graph.neighbors(id("gateway")).collect::<Vec<_>>(),
+        vec![id("auth"), id("cache")]
+    );
+
+    let components = graph.freeze().thaw().weakly_connected_components();
+    assert_eq!(components[&id("auth")], components[&id("cache")]);
+}
+
+#[test]
+fn string_ids_ending_in_colon() {
+    // Lines written without edge ids start with the start vertex, here `a:`. A line that
+    // reads both ways, like the last one, has an edge id.
+    let graph = OrientedGraph::<String, String, String>::deserialize(concat!(
+        "a: A\n",
+        "b B\n",
+        "7: Seven\n",
+        "#\n",
+        "a: b 5\n",
+        "7: b a: 6"
+    ))
+    .unwrap();
+    let edges: Vec<_> = graph
+        .edges()
+        .map(|edge| (edge.id(), edge.start_id(), edge.end_id()))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            (Some(0), Some(id("a:")), Some(id("b"))),
+            (Some(7), Some(id("b")), Some(id("a:")))
+        ]
+    );
+
+    let serialized = graph.serialize().unwrap();
+    assert_eq!(
+        serialized,
+        concat!(
+            "a: A\n",
+            "b B\n",
+            "7: Seven\n",
+            "#\n",
+            "0: a: b 5\n",
+            "7: b a: 6"
+        )
+    );
+    let reparsed = OrientedGraph::<String, String, String>::deserialize(&serialized).unwrap();
+    assert_eq!(reparsed.serialize().unwrap(), serialized);
+}
+
+#[test]
+fn narrow_numeric_ids() {
+    let policy = GraphPolicy {
+        reuse_vertex_ids: true,
+        ..GraphPolicy::default()
+    };
+    let mut graph = OrientedGraph::<String, String, u32>::with_policy(policy);
+    assert!(graph.add_raw_vertex(4, id("Four")).is_ok());
+    assert_eq!(graph.insert_vertex(id("Five")).unwrap(), 5u32);
+    assert!(graph.remove_vertex_by_id(4).is_ok());
+    assert_eq!(graph.insert_vertex(id("Four again")).unwrap(), 4u32);
+    assert_eq!(graph.insert_vertex(id("Six")).unwrap(), 6u32);
+}
+
+#[test]
+fn narrow_numeric_ids_run_out() {
+    let mut graph = OrientedGraph::<String, String, u16>::default();
+    assert!(graph
+        .add_raw_vertex(u16::MAX - 1, id("Before last"))
+        .is_ok());
+    assert_eq!(graph.insert_vertex(id("Last")).unwrap(), u16::MAX);
+    assert!(matches!(
+        graph.insert_vertex(id("Overflow")),
+        Err(GraphError::VertexIdsExhaustedError)
+    ));
+    assert_eq!(graph.vertex_count(), 2);
+    assert_eq!(graph.get_vertex_by_id(u16::MAX).unwrap().value(), "Last");
+}
+
+#[test]
+fn uuid_ids() {
+    let graph_str = concat!(
+        "67e55044-10b1-426f-9247-bb680e5fe0c8 First\n",
+        "936da01f-9abd-4d9d-80c7-02af85c822a8 Second\n",
+        "#\n",
+        "0: 67e55044-10b1-426f-9247-bb680e5fe0c8 936da01f-9abd-4d9d-80c7-02af85c822a8 link"
+    );
+    let graph = OrientedGraph::<String, String, Uuid>::deserialize(graph_str).unwrap();
+    assert_eq!(graph.serialize().unwrap(), graph_str);
+
+    let first: Uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+    let second: Uuid = "936da01f-9abd-4d9d-80c7-02af85c822a8".parse().unwrap();
+    assert_eq!(graph.neighbors(first).collect::<Vec<_>>(), vec![second]);
+}
+
+#[test]
+fn small_integer_ids() {
+    let mut graph = OrientedGraph::<String, String, u8>::default();
+    assert!(graph.add_raw_vertex(u8::MAX, id("Last")).is_ok());
+    assert!(matches!(
+        graph.insert_vertex(id("Overflow")),
+        Err(GraphError::VertexIdsExhaustedError)
+    ));
+
+    let mut graph = OrientedGraph::<String, String, i16>::default();
+    assert!(graph.add_raw_vertex(-1, id("Negative")).is_ok());
+    assert!(graph.add_raw_vertex(1, id("Positive")).is_ok());
+    assert!(graph.add_edge_with_vertex_id(-1, 1, None).is_ok());
+    assert_eq!(graph.neighbors(-1).collect::<Vec<_>>(), vec![1]);
+}
+
+#[test]
+fn unknown_vertex_in_edge_rejected() {
+    let graph = OrientedGraph::<String, String, String>::deserialize(concat!(
+        "gateway Gateway\n",
+        "#\n",
+        "gateway auth calls"
+    ));
+    assert!(graph.is_err());
+}
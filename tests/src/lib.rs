@@ -1,13 +1,47 @@
+#[cfg(test)]
+mod analytics;
+#[cfg(test)]
+mod arena;
+#[cfg(test)]
+mod connectivity;
+#[cfg(test)]
+mod csr;
+#[cfg(test)]
+mod cycles;
+#[cfg(test)]
+mod dominators;
+#[cfg(test)]
+mod flow;
+#[cfg(test)]
+mod ids;
+#[cfg(test)]
+mod matching;
+#[cfg(test)]
+mod paths;
+#[cfg(test)]
+mod shared;
+#[cfg(test)]
+mod spanning;
+#[cfg(test)]
+mod support;
+#[cfg(test)]
+mod transitive;
+
 #[cfg(test)]
 mod tests {
-    use graph_lib::graph::graph::{DefaultGraph, OrientedGraph};
+    use graph_lib::edge::edge::{DefaultEdge, DefaultOrientedEdge};
+    use graph_lib::error::{GraphError, GraphParseError};
+    use graph_lib::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
     use graph_lib::serde::serde_graph::{DeserializeGraph, SerializeGraph};
+    use graph_lib::vertex::vertex::DefaultVertex;
+
+    use crate::support::GRAPH_STR;
 
-    const GRAPH_STR: &str = concat!(
+    const GRAPH_STR_WITH_IDS: &str = concat!(
         "1 First vertex\n",
         "2 Second vertex\n",
         "#\n",
-        "1 2 Edge 1-2"
+        "0: 1 2 Edge 1-2"
     );
 
     #[test]
@@ -30,13 +64,13 @@ mod tests {
 
     #[test]
     fn get_vertex_by_id() {
-        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        let res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
         assert!(res.get_vertex_by_id(1).is_some());
     }
 
     #[test]
     fn get_vertex_not_found() {
-        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        let res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
         assert!(res.get_vertex_by_id(5).is_none());
     }
 
@@ -102,6 +136,301 @@ mod tests {
         let res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
         let ser_res = res.serialize();
         assert!(ser_res.is_ok());
-        assert_eq!(GRAPH_STR, ser_res.unwrap());
+        assert_eq!(GRAPH_STR_WITH_IDS, ser_res.unwrap());
+    }
+
+    #[test]
+    fn parallel_edges_rejected() {
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        assert!(matches!(
+            res.add_edge_with_vertex_id(1, 2, Some("Edge 1-2 again".to_string())),
+            Err(GraphError::EdgeExistsError)
+        ));
+        assert_eq!(res.edges_count(), 1);
+    }
+
+    #[test]
+    fn multigraph_parallel_edges() {
+        let mut res = OrientedGraph::<String, String>::multigraph();
+        assert!(res.add_raw_vertex(1, "First".to_string()).is_ok());
+        assert!(res.add_raw_vertex(2, "Second".to_string()).is_ok());
+
+        let first = res
+            .add_edge_with_vertex_id(1, 2, Some("Line A".to_string()))
+            .unwrap();
+        let second = res
+            .add_edge_with_vertex_id(1, 2, Some("Line B".to_string()))
+            .unwrap();
+        assert_ne!(first, second);
+        assert_eq!(res.edges_count(), 2);
+
+        assert_eq!(res.get_edges_by_vertexes_id(1, 2).count(), 2);
+        assert!(res.get_edges_by_vertexes_id(2, 1).next().is_none());
+
+        assert!(res.remove_edge_by_id(first).is_ok());
+        assert!(res.remove_edge_by_id(first).is_err());
+        assert_eq!(res.get_vertex_by_id(1).unwrap().edge_ids().count(), 1);
+        assert_eq!(res.get_vertex_by_id(2).unwrap().edge_ids().count(), 1);
+        assert_eq!(res.neighbors(1).collect::<Vec<_>>(), vec![2]);
+        let between: Vec<_> = res.get_edges_by_vertexes_id(1, 2).collect();
+        assert_eq!(between.len(), 1);
+        assert_eq!(between[0].id(), Some(second));
+        assert_eq!(between[0].value(), Some(&"Line B".to_string()));
+    }
+
+    #[test]
+    fn self_loop_added_once() {
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        assert!(res
+            .add_edge_with_vertex_id(2, 2, Some("Loop".to_string()))
+            .is_ok());
+        let vertex = res.get_vertex_by_id(2).unwrap();
+        assert_eq!(vertex.edge_ids().count(), 2);
+        assert_eq!(res.neighbors(2).collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn simple_policy_forbids_self_loops_and_parallel_edges() {
+        let mut res = OrientedGraph::<String, String>::with_policy(GraphPolicy::simple());
+        assert!(res.add_raw_vertex(1, "First".to_string()).is_ok());
+        assert!(res.add_raw_vertex(2, "Second".to_string()).is_ok());
+        assert!(matches!(
+            res.add_edge_with_vertex_id(1, 1, None),
+            Err(GraphError::SelfLoopError)
+        ));
+        assert!(res.add_edge_with_vertex_id(1, 2, None).is_ok());
+        assert!(matches!(
+            res.add_edge_with_vertex_id(1, 2, None),
+            Err(GraphError::EdgeExistsError)
+        ));
+        assert_eq!(res.edges_count(), 1);
+    }
+
+    #[test]
+    fn deserialize_with_policy() {
+        let graph = concat!("1 First vertex\n", "#\n", "1 1 Loop");
+        assert!(OrientedGraph::<String, String>::deserialize(graph).is_ok());
+        assert!(matches!(
+            OrientedGraph::<String, String>::deserialize_with_policy(graph, GraphPolicy::simple()),
+            Err(GraphParseError::EdgePolicyError(GraphError::SelfLoopError))
+        ));
+
+        let graph = concat!(
+            "1 First vertex\n",
+            "2 Second vertex\n",
+            "#\n",
+            "1 2 A\n",
+            "1 2 B"
+        );
+        assert!(matches!(
+            OrientedGraph::<String, String>::deserialize(graph),
+            Err(GraphParseError::EdgePolicyError(
+                GraphError::EdgeExistsError
+            ))
+        ));
+        let res = OrientedGraph::<String, String>::deserialize_with_policy(
+            graph,
+            GraphPolicy::multigraph(),
+        )
+        .unwrap();
+        assert_eq!(res.edges_count(), 2);
+    }
+
+    #[test]
+    fn edge_ids() {
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        assert!(res.add_raw_vertex(3, "Test".to_string()).is_ok());
+        let id = res
+            .add_edge_with_vertex_id(2, 3, Some("Edge 2-3".to_string()))
+            .unwrap();
+        assert_eq!(res.edge_ids().collect::<Vec<_>>(), vec![0, id]);
+
+        let edge = res.get_edge_by_id(id).unwrap();
+        assert_eq!(edge.start_id(), Some(2));
+        assert_eq!(edge.end_id(), Some(3));
+        drop(edge);
+
+        assert!(res.remove_edge_by_id(0).is_ok());
+        assert_eq!(res.edge_ids().collect::<Vec<_>>(), vec![id]);
+        assert!(res.get_edge_by_id(0).is_none());
+    }
+
+    #[test]
+    fn edge_ids_survive_serialization() {
+        let graph = concat!(
+            "1 First vertex\n",
+            "2 Second vertex\n",
+            "#\n",
+            "4: 1 2 Edge 1-2\n",
+            "7: 2 1 Edge 2-1"
+        );
+        let mut res = OrientedGraph::<String, String>::deserialize(graph).unwrap();
+        assert_eq!(res.edge_ids().collect::<Vec<_>>(), vec![4, 7]);
+        assert_eq!(res.serialize().unwrap(), graph);
+
+        assert!(res.remove_edge_by_id(7).is_ok());
+        let id = res.add_edge_with_vertex_id(2, 1, None).unwrap();
+        assert_eq!(id, 8);
+    }
+
+    #[test]
+    fn duplicate_edge_id_rejected() {
+        let graph = concat!(
+            "1 First vertex\n",
+            "2 Second vertex\n",
+            "#\n",
+            "0: 1 2 Edge 1-2\n",
+            "0: 2 1 Edge 2-1"
+        );
+        assert!(OrientedGraph::<String, String>::deserialize(graph).is_err());
+    }
+
+    #[test]
+    fn last_edge_id_rejected() {
+        let graph = concat!(
+            "1 First vertex\n",
+            "2 Second vertex\n",
+            "#\n",
+            "18446744073709551615: 1 2 x"
+        );
+        assert!(matches!(
+            OrientedGraph::<String, String>::deserialize(graph),
+            Err(GraphParseError::EdgeParsingError)
+        ));
+
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        let edge =
+            OrientedGraph::<String, String>::deserialize_edge("18446744073709551615: 2 1 x", &res)
+                .unwrap();
+        assert!(matches!(
+            res.add_edge(edge),
+            Err(GraphError::EdgeIdsExhaustedError)
+        ));
+        assert_eq!(res.edges_count(), 1);
+        assert_eq!(res.add_edge_with_vertex_id(2, 1, None).unwrap(), 1);
+    }
+
+    #[test]
+    fn insert_vertex_allocates_ids() {
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        let id = res.insert_vertex("Third vertex".to_string()).unwrap();
+        assert_eq!(id, 3);
+        assert!(res.add_raw_vertex(10, "Explicit".to_string()).is_ok());
+        assert_eq!(res.insert_vertex("Next".to_string()).unwrap(), 11);
+
+        assert!(res.remove_vertex_by_id(id).is_ok());
+        assert_eq!(res.insert_vertex("After removal".to_string()).unwrap(), 12);
+        assert_eq!(res.vertex_count(), 5);
+    }
+
+    #[test]
+    fn insert_vertex_after_last_id() {
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        assert!(res.add_raw_vertex(usize::MAX, "Last".to_string()).is_ok());
+        assert!(matches!(
+            res.insert_vertex("Overflow".to_string()),
+            Err(GraphError::VertexIdsExhaustedError)
+        ));
+        assert!(res.add_raw_vertex(3, "Explicit".to_string()).is_ok());
+        assert_eq!(res.vertex_count(), 4);
+    }
+
+    #[test]
+    fn insert_vertex_reuses_freed_ids() {
+        let mut res = OrientedGraph::<String, String>::with_policy(GraphPolicy {
+            reuse_vertex_ids: true,
+            ..GraphPolicy::default()
+        });
+        assert_eq!(res.insert_vertex("First".to_string()).unwrap(), 0);
+        assert_eq!(res.insert_vertex("Second".to_string()).unwrap(), 1);
+        assert_eq!(res.insert_vertex("Third".to_string()).unwrap(), 2);
+
+        assert!(res.remove_vertex_by_id(0).is_ok());
+        assert!(res.remove_vertex_by_id(1).is_ok());
+        assert_eq!(res.insert_vertex("Reused".to_string()).unwrap(), 0);
+        assert!(res.add_raw_vertex(1, "Explicit".to_string()).is_ok());
+        assert_eq!(res.insert_vertex("Fresh".to_string()).unwrap(), 3);
+    }
+
+    #[test]
+    fn update_vertex_and_edge() {
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        assert!(res
+            .update_vertex(1, |value| value.push_str(" (updated)"))
+            .is_ok());
+        assert_eq!(
+            res.get_vertex_by_id(1).unwrap().value(),
+            "First vertex (updated)"
+        );
+        assert!(res
+            .update_vertex(2, |value| *value = "Renamed".to_string())
+            .is_ok());
+        assert_eq!(res.get_vertex_by_id(2).unwrap().value(), "Renamed");
+
+        let mut vertex = res.get_vertex_by_id(1).unwrap().clone();
+        vertex.set_value("Copy".to_string());
+        vertex.value_mut().push('!');
+        assert_eq!(vertex.value(), "Copy!");
+        assert_eq!(
+            res.get_vertex_by_id(1).unwrap().value(),
+            "First vertex (updated)"
+        );
+
+        assert!(res
+            .update_edge(1, 2, |value| {
+                if let Some(value) = value {
+                    *value = "Edge 1-2 (updated)".to_string();
+                }
+            })
+            .is_ok());
+        assert_eq!(
+            res.get_edge_by_vertexes_id(1, 2).unwrap().value(),
+            Some(&"Edge 1-2 (updated)".to_string())
+        );
+
+        assert!(matches!(
+            res.update_vertex(5, |_| {}),
+            Err(GraphError::VertexNotFound)
+        ));
+        assert!(matches!(
+            res.update_edge(2, 1, |_| {}),
+            Err(GraphError::EdgeNotFound)
+        ));
+    }
+
+    #[test]
+    fn borrowing_iterators() {
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        assert!(res.add_raw_vertex(3, "Third vertex".to_string()).is_ok());
+        assert!(res.add_edge_with_vertex_id(1, 3, None).is_ok());
+        assert!(res.add_edge_with_vertex_id(3, 1, None).is_ok());
+
+        let ids: Vec<usize> = res.vertices().map(|vertex| vertex.id()).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(res.edges().count(), 3);
+        assert_eq!(res.neighbors(1).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(res.neighbors(2).count(), 0);
+        let out: Vec<Option<usize>> = res.out_edges(3).map(|edge| edge.end_id()).collect();
+        assert_eq!(out, vec![Some(1)]);
+    }
+
+    #[test]
+    fn removed_edge_leaves_vertexes() {
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        assert!(res.remove_edge_by_vertexes(1, 2).is_ok());
+        assert_eq!(res.get_vertex_by_id(1).unwrap().edge_ids().count(), 0);
+        assert_eq!(res.get_vertex_by_id(2).unwrap().edge_ids().count(), 0);
+        assert_eq!(res.neighbors(1).count(), 0);
+
+        let dfs_expect = "1 First vertex []\n";
+        assert_eq!(dfs_expect, res.depth_first_search(1).unwrap().to_string());
+    }
+
+    #[test]
+    fn removed_vertex_leaves_neighbors() {
+        let mut res = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+        assert!(res.remove_vertex_by_id(2).is_ok());
+        assert_eq!(res.edges_count(), 0);
+        assert_eq!(res.get_vertex_by_id(1).unwrap().edge_ids().count(), 0);
     }
 }
@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+
+use graph_lib::error::GraphError;
+use graph_lib::flow::flow::Flow;
+use graph_lib::graph::graph::{DefaultGraph, GraphView, OrientedGraph};
+use graph_lib::matching::matching::{Bipartition, Matching};
+use graph_lib::serde::serde_graph::DeserializeGraph;
+
+use crate::support::Rng;
+
+const SHIFTS_STR: &str = concat!(
+    "1 ann\n", "2 bob\n", "3 eve\n", "4 joe\n", "5 mon\n", "6 tue\n", "7 wed\n", "8 thu\n", "#\n",
+    "1 5 0\n", "1 6 0\n", "2 5 0\n", "3 6 0\n", "3 7 0\n", "3 8 0\n", "4 5 0\n", "8 3 0"
+);
+
+fn is_edge<G: GraphView<String, u32>>(graph: &G, first: usize, second: usize) -> bool {
+    graph.get_edge_by_vertexes_id(first, second).is_some()
+        || graph.get_edge_by_vertexes_id(second, first).is_some()
+}
+
+#[test]
+fn bipartition() {
+    let mut graph = OrientedGraph::<String, u32>::deserialize(SHIFTS_STR).unwrap();
+    let Bipartition::Sides { left, right } = graph.bipartition() else {
+        panic!("graph is bipartite");
+    };
+    assert_eq!(left, HashSet::from([1, 2, 3, 4]));
+    assert_eq!(right, HashSet::from([5, 6, 7, 8]));
+
+    graph.add_edge_with_vertex_id(6, 2, None).unwrap();
+    graph.add_edge_with_vertex_id(4, 2, None).unwrap();
+    let Bipartition::OddCycle(cycle) = graph.bipartition() else {
+        panic!("graph has an odd cycle");
+    };
+    assert_eq!(cycle.len() % 2, 1);
+    assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), cycle.len());
+    for (position, &vertex) in cycle.iter().enumerate() {
+        assert!(is_edge(&graph, vertex, cycle[(position + 1) % cycle.len()]));
+    }
+    assert!(matches!(
+        graph.maximum_matching(&left),
+        Err(GraphError::NotBipartiteError)
+    ));
+
+    let mut looped = OrientedGraph::<String, u32>::deserialize("1 A\n#\n").unwrap();
+    looped.add_edge_with_vertex_id(1, 1, None).unwrap();
+    assert!(matches!(looped.bipartition(), Bipartition::OddCycle(cycle) if cycle == vec![1]));
+}
+
+#[test]
+fn maximum_matching() {
+    let graph = OrientedGraph::<String, u32>::deserialize(SHIFTS_STR).unwrap();
+    let matching = graph
+        .maximum_matching(&HashSet::from([1, 2, 3, 4]))
+        .unwrap();
+    assert_eq!(matching.len(), 3);
+    assert!(matching.iter().any(|&(worker, _)| worker == 3));
+    let shifts: HashSet<usize> = matching.iter().map(|&(_, shift)| shift).collect();
+    assert_eq!(shifts.len(), 3);
+    assert!(shifts.contains(&5) && shifts.contains(&6));
+    for &(worker, shift) in &matching {
+        assert!(worker <= 4 && shift >= 5);
+        assert!(is_edge(&graph, worker, shift));
+    }
+}
+
+#[test]
+fn matching_size_equals_max_flow() {
+    let mut graph = OrientedGraph::<String, u32>::multigraph();
+    let mut network = OrientedGraph::<String, u32>::multigraph();
+    for id in 0..82 {
+        graph.add_raw_vertex(id, id.to_string()).unwrap();
+        network.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    // Workers 2..42 and shifts 42..82, with a source 0 and a sink 1 in the network.
+    for id in 2..42 {
+        network.add_edge_with_vertex_id(0, id, Some(1)).unwrap();
+        network
+            .add_edge_with_vertex_id(id + 40, 1, Some(1))
+            .unwrap();
+    }
+    for (worker, shift) in Rng::new(5).pairs(40, 70) {
+        let (worker, shift) = (2 + worker, 42 + shift);
+        graph.add_edge_with_vertex_id(shift, worker, None).unwrap();
+        network
+            .add_edge_with_vertex_id(worker, shift, Some(1))
+            .unwrap();
+    }
+
+    let matching = graph.maximum_matching(&(2..42).collect()).unwrap();
+    let flow = network.dinic(0, 1, |&capacity| capacity).unwrap();
+    assert_eq!(matching.len(), flow.value as usize);
+    let matched: HashSet<usize> = matching
+        .iter()
+        .flat_map(|&(first, second)| [first, second])
+        .collect();
+    assert_eq!(matched.len(), 2 * matching.len());
+    for &(worker, shift) in &matching {
+        assert!((2..42).contains(&worker) && (42..82).contains(&shift));
+    }
+}
+
+#[test]
+fn matching_sides_come_from_caller() {
+    // Worker 1 can take shifts 2 and 3, worker 5 shifts 4 and 6, and shift 7 is free.
+    // Component by component, the first vertex would put shift 4 on the left.
+    let graph = OrientedGraph::<String, u32>::deserialize(concat!(
+        "1 w1\n", "2 s1\n", "3 s2\n", "4 s3\n", "5 w2\n", "6 s4\n", "7 s5\n", "#\n", "1 2 0\n",
+        "1 3 0\n", "5 4 0\n", "6 5 0"
+    ))
+    .unwrap();
+    let workers = HashSet::from([1, 5]);
+    let matching = graph.maximum_matching(&workers).unwrap();
+    assert_eq!(matching.len(), 2);
+    for &(worker, shift) in &matching {
+        assert!(workers.contains(&worker) && !workers.contains(&shift));
+    }
+
+    assert!(matches!(
+        graph.maximum_matching(&HashSet::from([1, 2])),
+        Err(GraphError::NotBipartiteError)
+    ));
+    assert!(matches!(
+        graph.maximum_matching(&HashSet::from([1, 8])),
+        Err(GraphError::VertexNotFound)
+    ));
+}
+
+/// Ids of the workers of [`costs_graph`].
+fn workers(workers: usize) -> HashSet<usize> {
+    (1..=workers).collect()
+}
+
+/// Bipartite graph with `workers` on the left, ids from 1, and `tasks` on the right.
+fn costs_graph(
+    workers: usize,
+    tasks: usize,
+    costs: &[(usize, usize, i64)],
+) -> OrientedGraph<String, i64> {
+    let mut graph = OrientedGraph::multigraph();
+    for id in 1..=workers + tasks {
+        graph.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    for &(worker, task, cost) in costs {
+        graph
+            .add_edge_with_vertex_id(worker, workers + task, Some(cost))
+            .unwrap();
+    }
+    graph
+}
+
+#[test]
+fn min_cost_assignment() {
+    let square = costs_graph(
+        3,
+        3,
+        &[
+            (1, 1, 4),
+            (1, 2, 1),
+            (1, 3, 3),
+            (2, 1, 2),
+            (2, 2, 0),
+            (2, 3, 5),
+            (3, 1, 3),
+            (3, 2, 2),
+            (3, 3, 2),
+        ],
+    );
+    let assignment = square
+        .min_cost_assignment(&workers(3), |&cost| cost)
+        .unwrap();
+    assert_eq!(assignment.cost, 5);
+    let pairs: HashSet<(usize, usize)> = assignment.pairs.into_iter().collect();
+    assert_eq!(pairs, HashSet::from([(1, 5), (2, 4), (3, 6)]));
+
+    // More workers than tasks: one worker stays idle.
+    let wide = costs_graph(3, 2, &[(1, 1, 7), (2, 1, 3), (2, 2, 4), (3, 2, 1)]);
+    let assignment = wide.min_cost_assignment(&workers(3), |&cost| cost).unwrap();
+    assert_eq!(assignment.cost, 4);
+    let pairs: HashSet<(usize, usize)> = assignment.pairs.into_iter().collect();
+    assert_eq!(pairs, HashSet::from([(2, 4), (3, 5)]));
+
+    // Workers 1 and 2 can only take task 1.
+    let blocked = costs_graph(3, 3, &[(1, 1, 1), (2, 1, 1), (3, 2, 1), (3, 3, 1)]);
+    assert!(matches!(
+        blocked.min_cost_assignment(&workers(3), |&cost| cost),
+        Err(GraphError::InfeasibleAssignmentError)
+    ));
+}
+
+#[test]
+fn assignment_of_disconnected_graph() {
+    // Worker 1 can take shifts 2 and 3, worker 5 shifts 4 and 6, and nobody shift 7.
+    let graph = OrientedGraph::<String, i64>::deserialize(concat!(
+        "1 w1\n", "2 s1\n", "3 s2\n", "4 s3\n", "5 w2\n", "6 s4\n", "7 s5\n", "#\n", "1 2 4\n",
+        "1 3 2\n", "5 4 1\n", "6 5 3"
+    ))
+    .unwrap();
+    let assignment = graph
+        .min_cost_assignment(&HashSet::from([1, 5]), |&cost| cost)
+        .unwrap();
+    assert_eq!(assignment.cost, 3);
+    let pairs: HashSet<(usize, usize)> = assignment.pairs.into_iter().collect();
+    assert_eq!(pairs, HashSet::from([(1, 3), (5, 4)]));
+
+    assert!(matches!(
+        graph.min_cost_assignment(&HashSet::from([1, 3, 5]), |&cost| cost),
+        Err(GraphError::NotBipartiteError)
+    ));
+}
+
+fn cheapest_by_brute_force(
+    costs: &[Vec<Option<i64>>],
+    row: usize,
+    used: &mut Vec<bool>,
+) -> Option<i64> {
+    if row == costs.len() {
+        return Some(0);
+    }
+    let mut best = None;
+    for column in 0..used.len() {
+        let (Some(cost), false) = (costs[row][column], used[column]) else {
+            continue;
+        };
+        used[column] = true;
+        if let Some(rest) = cheapest_by_brute_force(costs, row + 1, used) {
+            best = Some(best.map_or(cost + rest, |best: i64| best.min(cost + rest)));
+        }
+        used[column] = false;
+    }
+    best
+}
+
+#[test]
+fn assignment_matches_brute_force() {
+    let mut rng = Rng::new(9);
+    for _ in 0..20 {
+        let mut matrix = vec![vec![None; 7]; 5];
+        let mut edges = Vec::new();
+        for (worker, row) in matrix.iter_mut().enumerate() {
+            for (task, cell) in row.iter_mut().enumerate() {
+                let seed = rng.step();
+                if (seed >> 60) < 11 {
+                    let cost = ((seed >> 20) % 50) as i64 - 10;
+                    *cell = Some(cost);
+                    edges.push((worker + 1, task + 1, cost));
+                }
+            }
+        }
+        let graph = costs_graph(5, 7, &edges);
+        let expected = cheapest_by_brute_force(&matrix, 0, &mut vec![false; 7]);
+        match graph.min_cost_assignment(&workers(5), |&cost| cost) {
+            Ok(assignment) => {
+                assert_eq!(Some(assignment.cost), expected);
+                assert_eq!(assignment.pairs.len(), 5);
+            }
+            Err(GraphError::InfeasibleAssignmentError) => assert_eq!(expected, None),
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
@@ -0,0 +1,422 @@
+use std::collections::HashSet;
+
+use graph_lib::analytics::analytics::Analytics;
+use graph_lib::edge::edge::DefaultEdge;
+use graph_lib::error::GraphError;
+use graph_lib::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
+use graph_lib::paths::paths::{AllPairs, Path, ShortestPaths};
+use graph_lib::serde::serde_graph::DeserializeGraph;
+use graph_lib::traversal::traversal::Traversal;
+
+use crate::support::{random_graph, random_multigraph};
+
+const MAP: [&str; 6] = [
+    "..#.....", //
+    "..#.##..", //
+    "..#..#..", //
+    "....#...", //
+    "######.#", //
+    "....#...",
+];
+
+/// Grid map in graph file form: every open cell is a vertex valued `x,y`, joined to its
+/// open neighbors by edges of weight 1, or 3 when entering the bottom row.
+fn grid() -> OrientedGraph<String, u32> {
+    let id = |x: usize, y: usize| y * 8 + x + 1;
+    let open = |x: usize, y: usize| MAP[y].as_bytes()[x] == b'.';
+    let mut vertices = String::new();
+    let mut edges = String::new();
+    for y in 0..MAP.len() {
+        for x in 0..8 {
+            if !open(x, y) {
+                continue;
+            }
+            vertices += &format!("{} {x},{y}\n", id(x, y));
+            for (nx, ny) in [
+                (x + 1, y),
+                (x, y + 1),
+                (x.wrapping_sub(1), y),
+                (x, y.wrapping_sub(1)),
+            ] {
+                if nx < 8 && ny < MAP.len() && open(nx, ny) {
+                    let weight = if ny == MAP.len() - 1 { 3 } else { 1 };
+                    edges += &format!("{} {} {weight}\n", id(x, y), id(nx, ny));
+                }
+            }
+        }
+    }
+    OrientedGraph::deserialize(&format!("{vertices}#\n{}", edges.trim_end())).unwrap()
+}
+
+fn manhattan(goal: (i64, i64)) -> impl Fn(&usize, &String) -> u32 {
+    move |_, value| {
+        let (x, y) = value.split_once(',').unwrap();
+        let (x, y): (i64, i64) = (x.parse().unwrap(), y.parse().unwrap());
+        ((x - goal.0).abs() + (y - goal.1).abs()) as u32
+    }
+}
+
+#[test]
+fn astar() {
+    let graph = grid();
+    let path = graph
+        .astar(1, 8, |&weight| weight, manhattan((7, 0)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(path.cost, 13);
+    assert_eq!(path.vertices.first(), Some(&1));
+    assert_eq!(path.vertices.last(), Some(&8));
+    assert_eq!(path.vertices.len(), 14);
+    for pair in path.vertices.windows(2) {
+        assert!(graph.get_edge_by_vertexes_id(pair[0], pair[1]).is_some());
+    }
+
+    let lengths = graph.shortest_path_lengths(1, |&weight| weight).unwrap();
+    for goal in [8, 28, 30, 48] {
+        let blind = graph
+            .astar(1, goal, |&weight| weight, |_, _| 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(blind.cost, lengths[&goal]);
+        let (x, y) = ((goal - 1) % 8, (goal - 1) / 8);
+        let guided = graph
+            .astar(1, goal, |&weight| weight, manhattan((x as i64, y as i64)))
+            .unwrap()
+            .unwrap();
+        assert_eq!(guided.cost, lengths[&goal]);
+    }
+
+    assert_eq!(
+        graph.astar(1, 1, |&weight| weight, |_, _| 0).unwrap(),
+        Some(Path {
+            vertices: vec![1],
+            cost: 0
+        })
+    );
+    // The left half of the bottom row is walled off.
+    assert_eq!(
+        graph.astar(1, 41, |&weight| weight, |_, _| 0).unwrap(),
+        None
+    );
+    assert!(matches!(
+        graph.astar(1, 3, |&weight| weight, |_, _| 0),
+        Err(GraphError::VertexNotFound)
+    ));
+}
+
+#[test]
+fn astar_negative_weight() {
+    // The negative edge 4 -> 5 lies past the goal 3, so the search never relaxes it.
+    let graph = OrientedGraph::<String, i32>::deserialize(concat!(
+        "1 A\n", "2 B\n", "3 C\n", "4 D\n", "5 E\n", "#\n", "1 2 1\n", "2 3 1\n", "1 4 5\n",
+        "4 5 -2"
+    ))
+    .unwrap();
+    let path = graph
+        .astar(1, 3, |&weight| weight, |_, _| 0)
+        .unwrap()
+        .unwrap();
+    assert_eq!(path.vertices, vec![1, 2, 3]);
+    assert!(matches!(
+        graph.astar(1, 5, |&weight| weight, |_, _| 0),
+        Err(GraphError::NegativeWeightError)
+    ));
+}
+
+const NEGATIVE_STR: &str = concat!(
+    "1 A\n", "2 B\n", "3 C\n", "4 D\n", "5 E\n", "#\n", "1 2 3\n", "1 3 8\n", "2 3 -4\n",
+    "3 4 2\n", "4 2 5\n", "1 4 1\n", "4 1 -1"
+);
+
+/// Checks that the path joins its ends by edges and costs what the table says.
+fn check_path<T, V: Copy + Into<i64>, G: GraphView<T, V>>(
+    graph: &G,
+    pairs: &AllPairs<usize, i64>,
+    start: usize,
+    end: usize,
+) {
+    let Some(path) = pairs.path(&start, &end) else {
+        assert_eq!(pairs.distance(&start, &end), None);
+        return;
+    };
+    assert_eq!(
+        (path.vertices[0], *path.vertices.last().unwrap()),
+        (start, end)
+    );
+    let cost: i64 = path
+        .vertices
+        .windows(2)
+        .map(|pair| {
+            graph
+                .get_edges_by_vertexes_id(pair[0], pair[1])
+                .map(|edge| (*edge.value().unwrap()).into())
+                .min()
+                .unwrap()
+        })
+        .sum();
+    assert_eq!(Some(cost), pairs.distance(&start, &end));
+    assert_eq!(path.cost, cost);
+}
+
+#[test]
+fn all_pairs_with_negative_weights() {
+    let mut graph = OrientedGraph::<String, i64>::deserialize(NEGATIVE_STR).unwrap();
+    for pairs in [
+        graph.floyd_warshall(|&weight| weight).unwrap(),
+        graph.johnson(|&weight| weight).unwrap(),
+    ] {
+        assert_eq!(pairs.distance(&1, &3), Some(-1));
+        assert_eq!(pairs.distance(&4, &3), Some(-2));
+        assert_eq!(pairs.distance(&3, &1), Some(1));
+        assert_eq!(pairs.distance(&5, &1), None);
+        assert_eq!(pairs.path(&4, &3).unwrap().vertices, vec![4, 1, 2, 3]);
+        for start in 1..=5 {
+            for end in 1..=5 {
+                check_path(&graph, &pairs, start, end);
+            }
+        }
+        assert_eq!(pairs.distances()[&1].len(), 4);
+        assert_eq!(pairs.eccentricity(&1), None);
+        assert_eq!(pairs.diameter(), None);
+        assert_eq!(pairs.radius(), None);
+        assert!(pairs.center().is_empty());
+    }
+
+    graph.add_edge_with_vertex_id(3, 1, Some(-1)).unwrap();
+    assert!(matches!(
+        graph.floyd_warshall(|&weight| weight),
+        Err(GraphError::NegativeCycleError)
+    ));
+    assert!(matches!(
+        graph.johnson(|&weight| weight),
+        Err(GraphError::NegativeCycleError)
+    ));
+}
+
+#[test]
+fn eccentricity_diameter_radius_center() {
+    // A road 1 - 2 - 3 - 4 - 5 with a shortcut 2 - 5 and a cul-de-sac 6 off 4.
+    let mut graph = OrientedGraph::<String, i64>::default();
+    for id in 1..=6 {
+        graph.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    for (start, end, weight) in [
+        (1, 2, 1),
+        (2, 3, 1),
+        (3, 4, 1),
+        (4, 5, 1),
+        (2, 5, 5),
+        (4, 6, 2),
+    ] {
+        graph
+            .add_edge_with_vertex_id(start, end, Some(weight))
+            .unwrap();
+        graph
+            .add_edge_with_vertex_id(end, start, Some(weight))
+            .unwrap();
+    }
+    let pairs = graph.johnson(|&weight| weight).unwrap();
+    assert_eq!(pairs.eccentricity(&1), Some(5));
+    assert_eq!(pairs.eccentricity(&3), Some(3));
+    assert_eq!(pairs.eccentricity(&4), Some(3));
+    assert_eq!(pairs.eccentricity(&7), None);
+    assert_eq!(pairs.diameter(), Some(5));
+    assert_eq!(pairs.radius(), Some(3));
+    assert_eq!(pairs.center(), vec![3, 4]);
+}
+
+#[test]
+fn all_pairs_agree() {
+    let graph = random_graph();
+    let lengths = graph
+        .all_shortest_path_lengths(|&weight| i64::from(weight))
+        .unwrap();
+    let floyd_warshall = graph.floyd_warshall(|&weight| i64::from(weight)).unwrap();
+    let johnson = graph.johnson(|&weight| i64::from(weight)).unwrap();
+    assert_eq!(floyd_warshall.distances(), lengths);
+    assert_eq!(johnson.distances(), lengths);
+    for (start, end) in [(0, 1), (3, 200), (17, 42), (5, 5), (260, 3)] {
+        check_path(&graph, &floyd_warshall, start, end);
+        check_path(&graph, &johnson, start, end);
+    }
+}
+
+const ROUTES_STR: &str = concat!(
+    "1 C\n", "2 D\n", "3 E\n", "4 F\n", "5 G\n", "6 H\n", "#\n", "1 2 3\n", "1 3 2\n", "2 4 4\n",
+    "3 2 1\n", "3 4 2\n", "3 5 3\n", "4 5 2\n", "4 6 1\n", "5 6 2"
+);
+
+#[test]
+fn k_shortest_paths() {
+    let graph = OrientedGraph::<String, u32>::deserialize(ROUTES_STR).unwrap();
+    let paths = graph.k_shortest_paths(1, 6, 3, |&weight| weight).unwrap();
+    let found: Vec<(Vec<usize>, u32)> = paths
+        .into_iter()
+        .map(|path| (path.vertices, path.cost))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (vec![1, 3, 4, 6], 5),
+            (vec![1, 3, 5, 6], 7),
+            (vec![1, 2, 4, 6], 8)
+        ]
+    );
+    assert_eq!(
+        graph
+            .k_shortest_paths(1, 6, 100, |&weight| weight)
+            .unwrap()
+            .len(),
+        7
+    );
+    assert!(graph
+        .k_shortest_paths(6, 1, 3, |&weight| weight)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        graph
+            .k_shortest_paths(2, 2, 3, |&weight| weight)
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+/// Costs of every simple path from `vertex` to `end`, trying each parallel edge.
+fn simple_path_costs(
+    graph: &OrientedGraph<String, u32>,
+    path: &mut Vec<usize>,
+    cost: u32,
+    end: usize,
+    costs: &mut Vec<u32>,
+) {
+    let vertex = *path.last().unwrap();
+    if vertex == end {
+        costs.push(cost);
+        return;
+    }
+    let mut next: Vec<usize> = graph.neighbors(vertex).collect();
+    next.sort_unstable();
+    next.dedup();
+    for next in next {
+        if path.contains(&next) {
+            continue;
+        }
+        let weight = graph
+            .get_edges_by_vertexes_id(vertex, next)
+            .map(|edge| *edge.value().unwrap())
+            .min()
+            .unwrap();
+        path.push(next);
+        simple_path_costs(graph, path, cost + weight, end, costs);
+        path.pop();
+    }
+}
+
+#[test]
+fn k_shortest_paths_match_brute_force() {
+    let graph = random_multigraph(21, 8, 26);
+    for (start, end) in [(0, 7), (3, 5), (6, 1)] {
+        let mut expected = Vec::new();
+        simple_path_costs(&graph, &mut vec![start], 0, end, &mut expected);
+        expected.sort_unstable();
+        assert!(!expected.is_empty());
+        let paths = graph
+            .k_shortest_paths(start, end, 12, |&weight| weight)
+            .unwrap();
+        let costs: Vec<u32> = paths.iter().map(|path| path.cost).collect();
+        assert_eq!(costs, expected[..expected.len().min(12)]);
+        let distinct: HashSet<&Vec<usize>> = paths.iter().map(|path| &path.vertices).collect();
+        assert_eq!(distinct.len(), paths.len());
+    }
+}
+
+const PERMISSIONS_STR: &str = concat!(
+    "1 admin\n",
+    "2 ops\n",
+    "3 dev\n",
+    "4 ci\n",
+    "5 deploy\n",
+    "#\n",
+    "1 2 grants\n",
+    "1 3 grants\n",
+    "2 3 grants\n",
+    "2 4 grants\n",
+    "3 4 grants\n",
+    "4 5 grants\n",
+    "3 1 grants\n",
+    "4 5 inherits"
+);
+
+#[test]
+fn all_simple_paths() {
+    let graph = OrientedGraph::<String, String>::deserialize_with_policy(
+        PERMISSIONS_STR,
+        GraphPolicy::multigraph(),
+    )
+    .unwrap();
+
+    let paths: HashSet<Vec<usize>> = graph.all_simple_paths(1, 5, usize::MAX).unwrap().collect();
+    assert_eq!(
+        paths,
+        HashSet::from([vec![1, 2, 4, 5], vec![1, 3, 4, 5], vec![1, 2, 3, 4, 5],])
+    );
+    let short: Vec<Vec<usize>> = graph.all_simple_paths(1, 5, 3).unwrap().collect();
+    assert_eq!(short.len(), 2);
+    assert!(short.iter().all(|path| path.len() == 4));
+    assert_eq!(graph.all_simple_paths(1, 5, 2).unwrap().count(), 0);
+    assert_eq!(
+        graph
+            .all_simple_paths(3, 2, usize::MAX)
+            .unwrap()
+            .collect::<Vec<_>>(),
+        vec![vec![3, 1, 2]]
+    );
+    assert_eq!(
+        graph.all_simple_paths(4, 4, 0).unwrap().collect::<Vec<_>>(),
+        vec![vec![4]]
+    );
+    assert!(graph.all_simple_paths(1, 6, 3).is_err());
+}
+
+#[test]
+fn simple_paths_are_lazy_and_complete() {
+    let graph = random_multigraph(21, 8, 26);
+    for (start, end) in [(0, 7), (3, 5), (6, 1)] {
+        let mut costs = Vec::new();
+        simple_path_costs(&graph, &mut vec![start], 0, end, &mut costs);
+        let paths: HashSet<Vec<usize>> = graph
+            .all_simple_paths(start, end, usize::MAX)
+            .unwrap()
+            .collect();
+        assert_eq!(paths.len(), costs.len());
+        assert!(paths.iter().all(|path| {
+            path.iter().collect::<HashSet<_>>().len() == path.len()
+                && path
+                    .windows(2)
+                    .all(|pair| graph.get_edge_by_vertexes_id(pair[0], pair[1]).is_some())
+        }));
+    }
+
+    // A complete graph has too many paths to list, but the first few come right away.
+    let mut complete = OrientedGraph::<String, u32>::default();
+    for id in 0..40 {
+        complete.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    for start in 0..40 {
+        for end in 0..40 {
+            if start != end {
+                complete.add_edge_with_vertex_id(start, end, None).unwrap();
+            }
+        }
+    }
+    let first: Vec<Vec<usize>> = complete
+        .all_simple_paths(0, 39, usize::MAX)
+        .unwrap()
+        .take(5)
+        .collect();
+    assert_eq!(first.len(), 5);
+    assert!(first
+        .iter()
+        .all(|path| path.first() == Some(&0) && path.last() == Some(&39)));
+}
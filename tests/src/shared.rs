@@ -0,0 +1,103 @@
+use std::thread;
+
+use graph_lib::graph::graph::{DefaultGraph, GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::DeserializeGraph;
+use graph_lib::shared::shared::SharedGraph;
+use graph_lib::vertex::vertex::DefaultVertex;
+
+use crate::support::{workload, GRAPH_STR};
+
+#[test]
+fn workload_matches_oriented_graph() {
+    let oriented = OrientedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    let shared = SharedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    assert_eq!(workload(shared), workload(oriented));
+}
+
+#[test]
+fn snapshot_is_isolated_from_writes() {
+    let mut graph = SharedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    let before = graph.snapshot();
+    let vertex = graph.get_vertex_by_id(1).unwrap();
+
+    assert!(graph.update_vertex(1, |value| value.push('!')).is_ok());
+    assert!(graph.remove_vertex_by_id(2).is_ok());
+
+    assert_eq!(vertex.value(), "First vertex");
+    assert_eq!(before.vertex_count(), 2);
+    assert_eq!(graph.vertex_count(), 1);
+    assert_eq!(graph.get_vertex_by_id(1).unwrap().value(), "First vertex!");
+}
+
+#[test]
+fn concurrent_readers_and_writer() {
+    let graph = SharedGraph::<String, String>::default();
+    for id in 0..100 {
+        graph.update(|g| g.add_raw_vertex(id, format!("Vertex {id}")).unwrap());
+    }
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let graph = graph.clone();
+            thread::spawn(move || {
+                for _ in 0..50 {
+                    // Every snapshot is consistent: each edge points at vertexes that exist.
+                    let snapshot = graph.snapshot();
+                    for vertex in snapshot.vertices() {
+                        for neighbor in snapshot.neighbors(vertex.id()) {
+                            assert!(snapshot.get_vertex_by_id(neighbor).is_some());
+                        }
+                    }
+                }
+            })
+        })
+        .collect();
+
+    let mut writer = graph.clone();
+    let handle = thread::spawn(move || {
+        for id in 0..99 {
+            writer.add_edge_with_vertex_id(id, id + 1, None).unwrap();
+            if id % 10 == 0 {
+                writer.remove_vertex_by_id(id).unwrap();
+            }
+        }
+    });
+
+    handle.join().unwrap();
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    assert_eq!(graph.vertex_count(), 90);
+    assert_eq!(graph.edges_count(), 80);
+}
+
+#[test]
+fn panicking_update_keeps_graph_usable() {
+    let mut graph = SharedGraph::<String, String>::deserialize(GRAPH_STR).unwrap();
+    let before = graph.snapshot();
+
+    let writer = graph.clone();
+    let result = thread::spawn(move || {
+        writer.update(|g| {
+            g.remove_vertex_by_id(2).unwrap();
+            panic!("update failed halfway");
+        })
+    })
+    .join();
+    assert!(result.is_err());
+    assert_eq!(graph.vertex_count(), 2);
+    assert_eq!(graph.edges_count(), 1);
+    drop(before);
+
+    let writer = graph.clone();
+    let result = thread::spawn(move || writer.update(|_| panic!("update failed"))).join();
+    assert!(result.is_err());
+    assert!(graph.insert_vertex("Third vertex".to_string()).is_ok());
+    assert_eq!(graph.vertex_count(), 3);
+}
+
+#[test]
+fn shared_graph_is_send_and_sync() {
+    fn assert_send_sync<G: Send + Sync>() {}
+    assert_send_sync::<SharedGraph<String, String>>();
+}
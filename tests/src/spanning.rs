@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+
+use graph_lib::edge::edge::{DefaultEdge, DefaultOrientedEdge};
+use graph_lib::graph::graph::{GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::DeserializeGraph;
+use graph_lib::spanning::spanning::SpanningTree;
+use graph_lib::traversal::traversal::Traversal;
+use graph_lib::union_find::union_find::UnionFind;
+
+use crate::support::random_graph;
+
+const NETWORK_STR: &str = concat!(
+    "1 A\n", "2 B\n", "3 C\n", "4 D\n", "5 E\n", "6 F\n", "7 G\n", "#\n", "1 2 4\n", "2 3 8\n",
+    "3 1 2\n", "4 3 5\n", "2 4 6\n", "4 4 0\n", "5 6 1\n", "6 5 3"
+);
+
+#[test]
+fn union_find() {
+    let mut sets = UnionFind::new(5);
+    assert_eq!(sets.set_count(), 5);
+    assert!(sets.union(0, 1));
+    assert!(sets.union(3, 4));
+    assert!(!sets.union(1, 0));
+    assert!(sets.connected(0, 1));
+    assert!(!sets.connected(1, 3));
+    assert!(sets.union(1, 4));
+    assert_eq!(sets.set_count(), 2);
+    assert_eq!(sets.set_size(3), 4);
+    assert_eq!(sets.set_size(2), 1);
+}
+
+#[test]
+fn spanning_forest() {
+    let graph = OrientedGraph::<String, u32>::deserialize(NETWORK_STR).unwrap();
+    for forest in [
+        graph.kruskal(|&weight| weight),
+        graph.prim(|&weight| weight),
+    ] {
+        assert_eq!(forest.total_weight, 2 + 4 + 5 + 1);
+        let ends: HashSet<(usize, usize)> = forest
+            .edges
+            .iter()
+            .map(|&id| {
+                let edge = graph.get_edge_by_id(id).unwrap();
+                (edge.start_id().unwrap(), edge.end_id().unwrap())
+            })
+            .collect();
+        assert_eq!(ends, HashSet::from([(3, 1), (1, 2), (4, 3), (5, 6)]));
+    }
+}
+
+#[test]
+fn kruskal_and_prim_agree() {
+    let graph = random_graph();
+    let kruskal = graph.kruskal(|&weight| weight);
+    let prim = graph.prim(|&weight| weight);
+    assert_eq!(kruskal.total_weight, prim.total_weight);
+
+    let components: HashSet<usize> = graph.weakly_connected_components().into_values().collect();
+    for forest in [kruskal, prim] {
+        assert_eq!(forest.edges.len(), graph.vertex_count() - components.len());
+        let distinct: HashSet<usize> = forest.edges.iter().copied().collect();
+        assert_eq!(distinct.len(), forest.edges.len());
+    }
+}
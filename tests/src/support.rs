@@ -0,0 +1,175 @@
+use std::fmt::Debug;
+
+use graph_lib::arena::arena::ArenaGraph;
+use graph_lib::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::SerializeGraph;
+use graph_lib::vertex::vertex::DefaultVertex;
+
+/// Seeded linear congruential generator, so randomized tests see the same input on every run.
+pub struct Rng(usize);
+
+impl Rng {
+    pub fn new(seed: usize) -> Self {
+        Self(seed)
+    }
+
+    /// Advances the generator and returns its new state.
+    pub fn step(&mut self) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0
+    }
+
+    /// Two numbers below `len` taken from one step.
+    pub fn pair(&mut self, len: usize) -> (usize, usize) {
+        let state = self.step();
+        ((state >> 33) % len, (state >> 17) % len)
+    }
+
+    /// `count` results of [`Rng::pair`], such as random edges between `len` vertexes.
+    pub fn pairs(&mut self, len: usize, count: usize) -> Vec<(usize, usize)> {
+        (0..count).map(|_| self.pair(len)).collect()
+    }
+}
+
+/// Pseudo-random multigraph with a few isolated islands.
+pub fn random_graph() -> ArenaGraph<usize, u32> {
+    let mut graph = ArenaGraph::with_policy(GraphPolicy::multigraph());
+    for id in 0..300 {
+        graph.add_raw_vertex(id, id).unwrap();
+    }
+    let mut rng = Rng::new(11);
+    for _ in 0..600 {
+        let state = rng.step();
+        let start = (state >> 33) % 250;
+        let end = (state >> 13) % 250;
+        graph
+            .add_edge_with_vertex_id(start, end, Some((state >> 40) as u32 % 100))
+            .unwrap();
+    }
+    graph
+}
+
+/// Multigraph on vertexes `0..len` with `count` random edges weighing less than 10.
+pub fn random_multigraph(seed: usize, len: usize, count: usize) -> OrientedGraph<String, u32> {
+    let mut graph = OrientedGraph::multigraph();
+    for id in 0..len {
+        graph.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    let mut rng = Rng::new(seed);
+    for _ in 0..count {
+        let state = rng.step();
+        graph
+            .add_edge_with_vertex_id(
+                (state >> 33) % len,
+                (state >> 17) % len,
+                Some((state >> 45) as u32 % 10),
+            )
+            .unwrap();
+    }
+    graph
+}
+
+/// Multigraph on `len` vertexes with the given edges, leaving out one vertex or edge.
+pub fn multigraph_without(
+    len: usize,
+    edges: &[(usize, usize)],
+    without_vertex: Option<usize>,
+    without_edge: Option<usize>,
+) -> OrientedGraph<String, u32> {
+    let mut graph = OrientedGraph::multigraph();
+    for id in (0..len).filter(|&id| Some(id) != without_vertex) {
+        graph.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    for (index, &(start, end)) in edges.iter().enumerate() {
+        if Some(index) != without_edge
+            && Some(start) != without_vertex
+            && Some(end) != without_vertex
+        {
+            graph.add_edge_with_vertex_id(start, end, None).unwrap();
+        }
+    }
+    graph
+}
+
+pub const GRAPH_STR: &str = concat!(
+    "1 First vertex\n",
+    "2 Second vertex\n",
+    "#\n",
+    "1 2 Edge 1-2"
+);
+
+/// Everything observable about a graph through the shared traits.
+pub fn snapshot<G>(graph: &G) -> String
+where
+    G: GraphView<String, String> + SerializeGraph<String, String>,
+{
+    let neighbors: Vec<(usize, Vec<usize>)> = graph
+        .vertices()
+        .map(|vertex| {
+            let id = vertex.id();
+            (id, graph.neighbors(id).collect())
+        })
+        .collect();
+    format!(
+        "{:?} {:?} {:?}",
+        graph.serialize().unwrap(),
+        neighbors,
+        graph.edge_ids().collect::<Vec<_>>()
+    )
+}
+
+/// Runs the scenarios of the main test suite plus a pseudo-random workload,
+/// recording the result of every operation.
+pub fn workload<G>(mut graph: G) -> Vec<String>
+where
+    G: DefaultGraph<String, String> + SerializeGraph<String, String>,
+{
+    let mut log = Vec::new();
+    let mut record = |step: &str, graph: &G, result: &dyn Debug| {
+        log.push(format!("{step}: {result:?} {}", snapshot(graph)));
+    };
+
+    let result = graph.add_raw_vertex(3, "Third vertex".to_string());
+    record("add_raw_vertex", &graph, &result);
+    let result = graph.add_edge_with_vertex_id(3, 2, Some("Edge 3-2".to_string()));
+    record("add_edge", &graph, &result);
+    let result = graph.add_edge_with_vertex_id(2, 3, Some("Edge 2-3".to_string()));
+    record("add_edge", &graph, &result);
+    let result = graph.add_edge_with_vertex_id(2, 3, None);
+    record("add_parallel_edge", &graph, &result);
+    let result = graph.remove_edge_by_vertexes(2, 3);
+    record("remove_edge_by_vertexes", &graph, &result);
+    let result = graph.add_edge_with_vertex_id(3, 3, Some("Loop".to_string()));
+    record("add_self_loop", &graph, &result);
+    let result = graph.update_vertex(1, |value| value.push('!'));
+    record("update_vertex", &graph, &result);
+    let result = graph.update_edge(3, 2, |value| value.unwrap().push('!'));
+    record("update_edge", &graph, &result);
+
+    let mut rng = Rng::new(7);
+    for step in 0..300 {
+        let (a, b) = rng.pair(12);
+        match step % 5 {
+            0 | 1 => {
+                let result = graph.add_edge_with_vertex_id(a, b, Some(format!("{a}-{b}")));
+                record("add_edge", &graph, &result);
+            }
+            2 => {
+                let result = graph.insert_vertex(format!("Vertex {step}"));
+                record("insert_vertex", &graph, &result);
+            }
+            3 => {
+                let result = graph.remove_vertex_by_id(a);
+                record("remove_vertex", &graph, &result);
+            }
+            _ => {
+                let result = graph.remove_edge_by_id(b);
+                record("remove_edge_by_id", &graph, &result);
+            }
+        }
+    }
+    log
+}
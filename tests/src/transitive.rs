@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use graph_lib::edge::edge::{DefaultEdge, DefaultOrientedEdge};
+use graph_lib::error::GraphError;
+use graph_lib::graph::graph::{DefaultGraph, GraphPolicy, GraphView, OrientedGraph};
+use graph_lib::serde::serde_graph::{DeserializeGraph, SerializeGraph};
+use graph_lib::traversal::traversal::Traversal;
+use graph_lib::vertex::vertex::DefaultVertex;
+
+use crate::support::Rng;
+
+const PACKAGES_STR: &str = concat!(
+    "1 app\n",
+    "2 web\n",
+    "3 db\n",
+    "4 core\n",
+    "5 log\n",
+    "#\n",
+    "1 2 direct\n",
+    "1 3 direct\n",
+    "1 4 direct\n",
+    "2 4 direct\n",
+    "3 4 direct\n",
+    "4 5 direct\n",
+    "1 5 direct\n",
+    "2 5 direct"
+);
+
+fn edge_set<T, V: Clone + Eq + Hash, G: GraphView<T, V>>(
+    graph: &G,
+) -> HashSet<(usize, usize, Option<V>)> {
+    graph
+        .edges()
+        .map(|edge| {
+            (
+                edge.start_id().unwrap(),
+                edge.end_id().unwrap(),
+                edge.value().cloned(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn transitive_reduction() {
+    let graph = OrientedGraph::<String, String>::deserialize(PACKAGES_STR).unwrap();
+    let reduced = graph.transitive_reduction().unwrap();
+    assert_eq!(reduced.vertex_count(), 5);
+    assert_eq!(
+        reduced.serialize().unwrap(),
+        concat!(
+            "1 app\n",
+            "2 web\n",
+            "3 db\n",
+            "4 core\n",
+            "5 log\n",
+            "#\n",
+            "0: 1 2 direct\n",
+            "1: 1 3 direct\n",
+            "3: 2 4 direct\n",
+            "4: 3 4 direct\n",
+            "5: 4 5 direct"
+        )
+    );
+    // Kept edges keep their ids.
+    let id = graph.get_edge_by_vertexes_id(4, 5).unwrap().id();
+    assert_eq!(reduced.get_edge_by_vertexes_id(4, 5).unwrap().id(), id);
+
+    let mut cyclic = OrientedGraph::<String, String>::deserialize(PACKAGES_STR).unwrap();
+    cyclic.add_edge_with_vertex_id(5, 2, None).unwrap();
+    assert!(matches!(
+        cyclic.transitive_reduction(),
+        Err(GraphError::CycleError)
+    ));
+}
+
+#[test]
+fn reduction_keeps_id_counters() {
+    let mut graph = OrientedGraph::<String, String>::deserialize(PACKAGES_STR).unwrap();
+    graph.add_raw_vertex(6, "test".to_string()).unwrap();
+    graph.remove_vertex_by_id(6).unwrap();
+    // Edges 6 and 7 are redundant, so neither id may come back.
+    let mut reduced = graph.transitive_reduction().unwrap();
+    assert_eq!(reduced.add_edge_with_vertex_id(5, 1, None).unwrap(), 8);
+    assert_eq!(reduced.insert_vertex("docs".to_string()).unwrap(), 7);
+
+    let mut closure = graph.transitive_closure(|_, _| None);
+    assert_eq!(closure.insert_vertex("docs".to_string()).unwrap(), 7);
+}
+
+#[test]
+fn transitive_closure() {
+    let graph = OrientedGraph::<String, String>::deserialize(
+        "1 app\n2 web\n3 db\n4 core\n#\n1 2 direct\n2 3 direct\n3 2 direct",
+    )
+    .unwrap();
+    let closure = graph.transitive_closure(|start, end| Some(format!("{start}->{end}")));
+    let expected: HashSet<(usize, usize, Option<String>)> = [
+        (1, 2, "direct"),
+        (2, 3, "direct"),
+        (3, 2, "direct"),
+        (1, 3, "1->3"),
+        (2, 2, "2->2"),
+        (3, 3, "3->3"),
+    ]
+    .into_iter()
+    .map(|(start, end, value)| (start, end, Some(value.to_string())))
+    .collect();
+    assert_eq!(edge_set(&closure), expected);
+    assert_eq!(closure.get_vertex_by_id(4).unwrap().value(), "core");
+
+    let policy = GraphPolicy {
+        self_loops: false,
+        ..GraphPolicy::default()
+    };
+    let strict = OrientedGraph::<String, String>::deserialize_with_policy(
+        "1 web\n2 db\n#\n1 2 direct\n2 1 direct",
+        policy,
+    )
+    .unwrap();
+    assert_eq!(strict.transitive_closure(|_, _| None).edges_count(), 2);
+}
+
+#[test]
+fn reduction_keeps_reachability() {
+    let mut graph = OrientedGraph::<String, String>::with_policy(GraphPolicy::multigraph());
+    for id in 0..30 {
+        graph.add_raw_vertex(id, id.to_string()).unwrap();
+    }
+    for (first, second) in Rng::new(41).pairs(30, 120) {
+        if first != second {
+            graph
+                .add_edge_with_vertex_id(first.min(second), first.max(second), None)
+                .unwrap();
+        }
+    }
+    let reduced = graph.transitive_reduction().unwrap();
+    let closure = graph.transitive_closure(|_, _| None);
+    assert_eq!(
+        edge_set(&reduced.transitive_closure(|_, _| None)),
+        edge_set(&closure)
+    );
+    for id in 0..30 {
+        assert_eq!(
+            reduced.reachable_from(id).unwrap(),
+            graph.reachable_from(id).unwrap()
+        );
+    }
+    // No kept edge can be replaced by a longer path.
+    for edge in reduced.edges() {
+        let (start, end) = (edge.start_id().unwrap(), edge.end_id().unwrap());
+        let detours = reduced
+            .neighbors(start)
+            .filter(|&next| next != end)
+            .any(|next| reduced.is_reachable(next, end).unwrap());
+        assert!(!detours);
+    }
+}